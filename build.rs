@@ -0,0 +1,36 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Collects the Rust sources below `dir`, inputs and fixtures do not change
+/// how answers are found.
+fn sources(dir: &Path, ret: &mut Vec<PathBuf>) {
+    let entries = fs::read_dir(dir).expect("Unreadable source directory");
+    for entry in entries {
+        let path = entry.expect("Unreadable source directory").path();
+        if path.is_dir() {
+            sources(&path, ret);
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            ret.push(path);
+        }
+    }
+}
+
+/// Sets `AOC_BUILD_ID` to an FNV-1a hash of the crate sources, the answer
+/// cache drops the entries of other builds.
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    let mut paths = vec![PathBuf::from("Cargo.toml")];
+    sources(Path::new("src"), &mut paths);
+    paths.sort();
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for path in paths {
+        let contents = fs::read(&path).expect("Unreadable source file");
+        // the separator keeps a path apart from the contents of its file
+        let name = path.to_string_lossy();
+        for b in name.as_bytes().iter().chain(&[0]).chain(&contents) {
+            hash = (hash ^ *b as u64).wrapping_mul(0x100000001b3);
+        }
+    }
+    println!("cargo:rustc-env=AOC_BUILD_ID={:016x}", hash);
+}
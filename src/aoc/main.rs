@@ -0,0 +1,557 @@
+use std::env;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc24rust::bench::{bench, parse_duration, Budget};
+use aoc24rust::cache::{default_cache_path, Cache};
+use aoc24rust::cancel::Token;
+use aoc24rust::generate::generate;
+use aoc24rust::history::{
+    current_commit, default_history_path, resolve_commit, Entry, History, Verdict,
+    COMPARISON_HEADER,
+};
+use aoc24rust::image::save_frames;
+use aoc24rust::input::InputSource;
+use aoc24rust::params::{parse_assignment, Listing, Overrides, Params};
+use aoc24rust::render::{animation, Player};
+use aoc24rust::report::{catch_panic, run_parallel, table, Format, Outcome, Record};
+use aoc24rust::solution::{DAYS, PARAMS};
+use aoc24rust::trace::{self, Filter};
+use aoc24rust::verify::{default_answers_path, Check, KnownAnswers};
+
+const USAGE: &str = "Usage: aoc [--trace FILTER] COMMAND ...
+       aoc run [--format FORMAT] [--jobs JOBS] [--timeout TIME] [--recompute] [PARAMS] [DAYS [INPUT]]
+       aoc bench [--budget BUDGET] [PARAMS] [DAYS [INPUT]]
+       aoc verify [--answers FILE] [--timeout TIME] [DAYS]
+       aoc compare --baseline COMMIT [--commit COMMIT] [DAYS]
+       aoc animate [--fps FPS] [--frames FRAMES] [--skip SKIP] [--out DIR] [PARAMS] DAY [INPUT]
+       aoc generate [--seed SEED] [--size SIZE] DAY
+       aoc params [DAYS]
+
+DAYS is a single day (16), a range (1-25) or a comma separated list
+of both (1-5,16). All days are run when it is omitted.
+INPUT is an input file for a single day, `-` reads it from stdin.
+FORMAT is text (default), json (one object per line) or csv.
+JOBS runs the days on that many threads, text output is then a single
+table sorted by day.
+BUDGET is a number of iterations (20) or a time per day (500ms, 5s),
+the default is 10 iterations. The times of the default inputs without
+PARAMS are added to bench_history.txt in the crate root, keyed by the
+checked out commit (with -dirty for uncommitted changes).
+TIME limits each day (500ms, 5s), a day still searching then gives up.
+run keeps the answers in target/aoc-cache.txt and shows them again for
+the same input and parameters as long as the sources of the build are
+unchanged, a cache that does not parse is ignored. --recompute solves
+all days again and replaces their answers.
+verify compares the answers for the default inputs with FILE, which
+defaults to answers.txt in the crate root.
+compare checks the history of COMMIT, the checked out one by default,
+against the baseline COMMIT (a hash or a revision like HEAD~1). It fails
+when a phase of a day got significantly slower.
+animate plays the simulation of days 6, 14 and 18 in the terminal at
+FPS frames per second (default 10), stopping after FRAMES frames. The
+first SKIP frames are not shown. With DIR the frames are written there
+as numbered PPM images instead, this needs FRAMES as day 14 never ends.
+generate writes a random input for DAY to stdout, the same SEED (default
+0) always gives the same input. SIZE scales it, the default is the size
+of the real input.
+PARAMS changes the puzzle, e.g. to the smaller grid of an example:
+--params FILE reads DAY NAME VALUE lines and every --param NAME=VALUE
+sets NAME for all DAYS that have it. params lists the parameters and
+their defaults in the format of FILE.
+FILTER selects the debug events written to stderr, like info for the
+run times or info,day16=debug,utils::search=trace for more detail. It
+defaults to the AOC_TRACE environment variable.";
+
+/// Pixels per cell of exported frames.
+const IMAGE_SCALE: usize = 4;
+
+/// Removes `--name VALUE` from `args` and returns the value.
+fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    let Some(idx) = args.iter().position(|a| a == name) else {
+        return Ok(None);
+    };
+    if idx + 1 >= args.len() {
+        return Err(format!("Missing value for {}", name));
+    }
+    args.remove(idx);
+    Ok(Some(args.remove(idx)))
+}
+
+/// Removes all `--name` from `args` and returns whether there was one.
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let len = args.len();
+    args.retain(|a| a != name);
+    args.len() != len
+}
+
+/// Removes all `--name VALUE` from `args` and returns the values.
+fn take_all(args: &mut Vec<String>, name: &str) -> Result<Vec<String>, String> {
+    let mut ret = Vec::new();
+    while let Some(value) = take_option(args, name)? {
+        ret.push(value);
+    }
+    Ok(ret)
+}
+
+fn parse_days(arg: &str) -> Result<Vec<u32>, String> {
+    let all: RangeInclusive<u32> = 1..=DAYS.len() as u32;
+    let mut ret = Vec::new();
+    for part in arg.split(',') {
+        let parse = |s: &str| -> Result<u32, String> {
+            match s.trim().parse::<u32>() {
+                Ok(day) if all.contains(&day) => Ok(day),
+                _ => Err(format!("Invalid day '{}'", s)),
+            }
+        };
+        match part.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse(first)?, parse(last)?);
+                if first > last {
+                    return Err(format!(
+                        "Invalid range '{}', {} comes after {}",
+                        part, first, last
+                    ));
+                }
+                ret.extend(first..=last);
+            }
+            None => ret.push(parse(part)?),
+        }
+    }
+    Ok(ret)
+}
+
+fn check_input(days: &[u32], input: Option<&str>) -> Result<(), String> {
+    if input.is_some() && days.len() != 1 {
+        return Err("An input file can only be given for a single day".to_string());
+    }
+    Ok(())
+}
+
+/// The parameters of `days` from the `file` and the `assignments`, which
+/// take precedence.
+fn read_overrides(
+    days: &[u32],
+    file: Option<&str>,
+    assignments: &[String],
+) -> Result<Overrides, String> {
+    let mut ret = match file {
+        Some(path) => Overrides::read(path.as_ref())?,
+        None => Overrides::new(),
+    };
+    for arg in assignments {
+        let (name, value) = parse_assignment(arg)?;
+        let matching = days
+            .iter()
+            .filter(|day| PARAMS[**day as usize - 1].iter().any(|p| p.name == name))
+            .collect::<Vec<&u32>>();
+        if matching.is_empty() {
+            return Err(format!("No parameter '{}' for the given days", name));
+        }
+        for day in matching {
+            ret.set(*day, name, value);
+        }
+    }
+    ret.check()?;
+    Ok(ret)
+}
+
+fn print_record(record: &Record, format: Format) {
+    if record.is_ok() || format != Format::Text {
+        println!("{}", format.record(record));
+    } else {
+        eprintln!("{}", format.record(record));
+    }
+}
+
+/// The token of one day, created right before it runs.
+fn token(timeout: Option<Duration>) -> Token {
+    timeout.map_or_else(Token::unlimited, Token::with_budget)
+}
+
+/// Runs all `days`, failing days are reported and do not stop the others.
+/// Answers found before are taken from the `cache`, which is saved afterwards.
+fn run(
+    days: &[u32],
+    input: Option<&str>,
+    overrides: &Overrides,
+    timeout: Option<Duration>,
+    cache: &Cache,
+    format: Format,
+    jobs: Option<usize>,
+) -> Result<(), String> {
+    check_input(days, input)?;
+    if let Some(header) = format.header() {
+        println!("{}", header);
+    }
+    let records = match jobs {
+        Some(jobs) => {
+            let records = run_parallel(days, input, overrides, timeout, Some(cache), jobs);
+            match format {
+                Format::Text => println!("{}", table(&records)),
+                _ => records.iter().for_each(|r| print_record(r, format)),
+            }
+            records
+        }
+        None => days
+            .iter()
+            .map(|day| {
+                let source = InputSource::from_arg(*day, input);
+                let params = overrides.day(*day);
+                let record = Record::run(*day, &source, &params, &token(timeout), Some(cache));
+                print_record(&record, format);
+                record
+            })
+            .collect(),
+    };
+    cache.save()?;
+    let failed = records.iter().filter(|r| !r.is_ok()).count();
+    match failed {
+        0 => Ok(()),
+        1 if days.len() == 1 => Err(String::new()),
+        _ => Err(format!("{} of {} days failed", failed, days.len())),
+    }
+}
+
+/// Benchmarks all `days`, failing days are reported and do not stop the others.
+fn bench_days(
+    days: &[u32],
+    input: Option<&str>,
+    overrides: &Overrides,
+    budget: &Budget,
+) -> Result<(), String> {
+    check_input(days, input)?;
+    let commit = current_commit();
+    let mut failed = 0;
+    for day in days {
+        let source = InputSource::from_arg(*day, input);
+        let params = overrides.day(*day);
+        let result = match source.read() {
+            Ok(text) => {
+                match catch_panic(|| bench(DAYS[*day as usize - 1], &text, &params, budget)) {
+                    Ok(Ok(result)) => Ok(result),
+                    Ok(Err(e)) => Err(e.to_string()),
+                    Err(message) => Err(format!("panicked: {}", message)),
+                }
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        let result = match result {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                failed += 1;
+                continue;
+            }
+        };
+        println!("{}", result);
+        // other inputs and parameters would not be comparable
+        if source == InputSource::Default(*day) && params.is_empty() {
+            History::append(
+                &default_history_path(),
+                &Entry::from_bench(&commit, &result),
+            )?;
+        }
+    }
+    match failed {
+        0 => Ok(()),
+        1 if days.len() == 1 => Err(String::new()),
+        _ => Err(format!("{} of {} days failed", failed, days.len())),
+    }
+}
+
+/// Compares the bench history of `commit` with `baseline` for all `days`.
+fn compare(days: &[u32], baseline: &str, commit: Option<&str>) -> Result<(), String> {
+    let history = History::read(&default_history_path())?;
+    let baseline = resolve_commit(baseline);
+    let commit = commit.map_or_else(current_commit, resolve_commit);
+    let comparisons = history.compare(&baseline, &commit, days);
+    if comparisons.is_empty() {
+        return Err(format!(
+            "No days benchmarked at both {} and {}",
+            baseline, commit
+        ));
+    }
+    println!("{} against {}", commit, baseline);
+    println!("{}", COMPARISON_HEADER);
+    for comparison in comparisons.iter() {
+        println!("{}", comparison);
+    }
+    let regressions = comparisons
+        .iter()
+        .filter(|c| c.verdict == Verdict::Regression)
+        .count();
+    match regressions {
+        0 => Ok(()),
+        _ => Err(format!("{} phases got slower", regressions)),
+    }
+}
+
+/// Checks the answers for the default inputs of all `days`.
+fn verify(
+    days: &[u32],
+    answers_path: Option<&str>,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    let known = match answers_path {
+        Some(path) => KnownAnswers::read(path.as_ref())?,
+        None => KnownAnswers::read(&default_answers_path())?,
+    };
+    let (mut correct, mut wrong, mut missing, mut failed) = (0, 0, 0, 0);
+    for day in days {
+        let source = InputSource::Default(*day);
+        let record = Record::run(*day, &source, &Params::new(), &token(timeout), None);
+        let Outcome::Solved(answers) = &record.outcome else {
+            println!("{}", Format::Text.record(&record));
+            failed += 1;
+            continue;
+        };
+        let checks = known.check_answers(answers);
+        for (part, check) in checks.iter().enumerate() {
+            println!("Day {:02} part{}: {}", day, part + 1, check);
+            match check {
+                Check::Correct => correct += 1,
+                Check::Wrong { .. } => wrong += 1,
+                Check::Unknown | Check::Unsolved => missing += 1,
+            }
+        }
+    }
+    println!(
+        "{} correct, {} wrong, {} missing, {} failed",
+        correct, wrong, missing, failed
+    );
+    match wrong + failed {
+        0 => Ok(()),
+        _ => Err(String::new()),
+    }
+}
+
+fn animate(
+    days: &[u32],
+    input: Option<&str>,
+    overrides: &Overrides,
+    player: &Player,
+    skip: usize,
+    out: Option<&str>,
+) -> Result<(), String> {
+    let [day] = days[..] else {
+        return Err("Only a single day can be animated".to_string());
+    };
+    let animation = animation(day).ok_or(format!("Day {} has no animation", day))?;
+    let input = InputSource::from_arg(day, input)
+        .read()
+        .map_err(|e| format!("Day {}: {}", day, e))?;
+    let mut sim =
+        animation(&input, &overrides.day(day)).map_err(|e| format!("Day {}: {}", day, e))?;
+    if let Some(dir) = out {
+        let Some(frames) = player.max_frames else {
+            return Err("--out needs --frames, a simulation may never end".to_string());
+        };
+        let end = skip + frames;
+        let prefix = format!("day{:02}", day);
+        let paths = save_frames(
+            sim.as_mut(),
+            skip..end,
+            IMAGE_SCALE,
+            Path::new(dir),
+            &prefix,
+        )
+        .map_err(|e| e.to_string())?;
+        println!("Wrote {} frames to {}", paths.len(), dir);
+        return Ok(());
+    }
+    for _ in 0..skip {
+        if !sim.advance() {
+            break;
+        }
+    }
+    player
+        .play(sim.as_mut(), &mut io::stdout().lock())
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn generate_input(days: &[u32], seed: u64, size: Option<usize>) -> Result<(), String> {
+    let [day] = days[..] else {
+        return Err("Inputs can only be generated for a single day".to_string());
+    };
+    let input = generate(day, seed, size).ok_or(format!("Day {} has no generator", day))?;
+    println!("{}", input);
+    Ok(())
+}
+
+/// Lists the parameters of all `days` that have any.
+fn list_params(days: &[u32]) -> Result<(), String> {
+    let listings = days
+        .iter()
+        .filter(|day| !PARAMS[**day as usize - 1].is_empty())
+        .map(|day| {
+            Listing {
+                day: *day,
+                params: PARAMS[*day as usize - 1],
+            }
+            .to_string()
+        })
+        .collect::<Vec<String>>();
+    if listings.is_empty() {
+        return Err("None of the days has parameters".to_string());
+    }
+    println!("{}", listings.join("\n\n"));
+    Ok(())
+}
+
+fn main_with_args(mut args: Vec<String>) -> Result<(), String> {
+    trace::init_from_env()?;
+    if let Some(filter) = take_option(&mut args, "--trace")? {
+        trace::set_filter(filter.parse::<Filter>()?);
+    }
+    let format = take_option(&mut args, "--format")?;
+    let budget = take_option(&mut args, "--budget")?;
+    let answers = take_option(&mut args, "--answers")?;
+    let baseline = take_option(&mut args, "--baseline")?;
+    let commit = take_option(&mut args, "--commit")?;
+    let params_file = take_option(&mut args, "--params")?;
+    let assignments = take_all(&mut args, "--param")?;
+    let recompute = take_flag(&mut args, "--recompute");
+    let fps = match take_option(&mut args, "--fps")?
+        .as_deref()
+        .map(str::parse::<f64>)
+    {
+        None => None,
+        Some(Ok(fps)) if fps >= 0.0 => Some(fps),
+        Some(_) => return Err("FPS has to be a non negative number".to_string()),
+    };
+    let out = take_option(&mut args, "--out")?;
+    let skip = match take_option(&mut args, "--skip")?
+        .as_deref()
+        .map(str::parse::<usize>)
+    {
+        None => None,
+        Some(Ok(skip)) => Some(skip),
+        Some(_) => return Err("SKIP has to be a number".to_string()),
+    };
+    let frames = match take_option(&mut args, "--frames")?
+        .as_deref()
+        .map(str::parse::<usize>)
+    {
+        None => None,
+        Some(Ok(frames)) if frames > 0 => Some(frames),
+        Some(_) => return Err("FRAMES has to be a positive number".to_string()),
+    };
+    let seed = match take_option(&mut args, "--seed")?
+        .as_deref()
+        .map(str::parse::<u64>)
+    {
+        None => None,
+        Some(Ok(seed)) => Some(seed),
+        Some(_) => return Err("SEED has to be a number".to_string()),
+    };
+    let size = match take_option(&mut args, "--size")?
+        .as_deref()
+        .map(str::parse::<usize>)
+    {
+        None => None,
+        Some(Ok(size)) if size > 0 => Some(size),
+        Some(_) => return Err("SIZE has to be a positive number".to_string()),
+    };
+    let timeout = match take_option(&mut args, "--timeout")? {
+        None => None,
+        Some(time) => Some(parse_duration(&time).ok_or(format!("Invalid time '{}'", time))?),
+    };
+    let jobs = match take_option(&mut args, "--jobs")?
+        .as_deref()
+        .map(str::parse::<usize>)
+    {
+        None => None,
+        Some(Ok(jobs)) if jobs > 0 => Some(jobs),
+        Some(_) => return Err("JOBS has to be a positive number".to_string()),
+    };
+    let (command, days, input) = match args.iter().map(String::as_str).collect::<Vec<&str>>()[..] {
+        [command] => (command, (1..=DAYS.len() as u32).collect::<Vec<u32>>(), None),
+        [command, days] => (command, parse_days(days)?, None),
+        [command, days, input] => (command, parse_days(days)?, Some(input)),
+        _ => return Err(USAGE.to_string()),
+    };
+    match (command, format, budget) {
+        _ if answers.is_some() && command != "verify" => Err(USAGE.to_string()),
+        _ if (jobs.is_some() || recompute) && command != "run" => Err(USAGE.to_string()),
+        _ if (baseline.is_some() || commit.is_some()) && command != "compare" => {
+            Err(USAGE.to_string())
+        }
+        _ if timeout.is_some() && !["run", "verify"].contains(&command) => Err(USAGE.to_string()),
+        _ if [
+            fps.is_some(),
+            frames.is_some(),
+            skip.is_some(),
+            out.is_some(),
+        ]
+        .contains(&true)
+            && command != "animate" =>
+        {
+            Err(USAGE.to_string())
+        }
+        _ if (seed.is_some() || size.is_some()) && command != "generate" => Err(USAGE.to_string()),
+        _ if (params_file.is_some() || !assignments.is_empty())
+            && !["run", "bench", "animate"].contains(&command) =>
+        {
+            Err(USAGE.to_string())
+        }
+        ("verify", None, None) if input.is_none() => verify(&days, answers.as_deref(), timeout),
+        ("run", format, None) => {
+            let format = format.as_deref().unwrap_or("text").parse::<Format>()?;
+            let overrides = read_overrides(&days, params_file.as_deref(), &assignments)?;
+            let path = default_cache_path();
+            let mut cache = Cache::read(&path).unwrap_or_else(|e| {
+                eprintln!("Ignoring the answer cache, {}", e);
+                Cache::new(&path)
+            });
+            cache.set_recompute(recompute);
+            run(&days, input, &overrides, timeout, &cache, format, jobs)
+        }
+        ("bench", None, budget) => {
+            let budget = budget.as_deref().unwrap_or("10").parse::<Budget>()?;
+            let overrides = read_overrides(&days, params_file.as_deref(), &assignments)?;
+            bench_days(&days, input, &overrides, &budget)
+        }
+        ("animate", None, None) if args.len() > 1 => {
+            let player = Player {
+                fps: fps.unwrap_or(10.0),
+                max_frames: frames,
+            };
+            let overrides = read_overrides(&days, params_file.as_deref(), &assignments)?;
+            animate(
+                &days,
+                input,
+                &overrides,
+                &player,
+                skip.unwrap_or(0),
+                out.as_deref(),
+            )
+        }
+        ("generate", None, None) if args.len() == 2 => {
+            generate_input(&days, seed.unwrap_or(0), size)
+        }
+        ("params", None, None) if input.is_none() => list_params(&days),
+        ("compare", None, None) if input.is_none() => match baseline {
+            Some(baseline) => compare(&days, &baseline, commit.as_deref()),
+            None => Err(USAGE.to_string()),
+        },
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn main() -> ExitCode {
+    let result = main_with_args(env::args().skip(1).collect());
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            if !e.is_empty() {
+                eprintln!("{}", e);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::cancel::Token;
+use crate::params::Params;
+use crate::solution::{Answers, SolveError, Solver};
+
+/// How long a day is benchmarked, at least one iteration is always run.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Budget {
+    Iterations(usize),
+    Time(Duration),
+}
+
+impl FromStr for Budget {
+    type Err = String;
+
+    /// `20` is a number of iterations, `500ms` or `5s` a time budget.
+    fn from_str(s: &str) -> Result<Budget, String> {
+        let invalid = || format!("Invalid budget '{}'", s);
+        if s.ends_with('s') {
+            return parse_duration(s).map(Budget::Time).ok_or_else(invalid);
+        }
+        match s.parse::<usize>() {
+            Ok(n) if n > 0 => Ok(Budget::Iterations(n)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Parses a time like `500ms` or `1.5s`.
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    if let Some(ms) = s.strip_suffix("ms") {
+        return ms.parse::<u64>().ok().map(Duration::from_millis);
+    }
+    let secs = s.strip_suffix('s')?.parse::<f64>().ok()?;
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+/// Summary of the samples of one phase.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Stats {
+        if samples.is_empty() {
+            return Stats {
+                min: Duration::ZERO,
+                median: Duration::ZERO,
+                mean: Duration::ZERO,
+                stddev: Duration::ZERO,
+            };
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let len = sorted.len();
+        let median = match len % 2 {
+            0 => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+            _ => sorted[len / 2],
+        };
+        let mean = sorted.iter().sum::<Duration>() / len as u32;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / len as f64;
+        Stats {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub struct Bench {
+    pub answers: Answers,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    /// The times of parse, part1 and part2 in the order they were measured.
+    pub samples: [Vec<Duration>; 3],
+}
+
+impl Display for Bench {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Day {:02} ({} iterations)",
+            self.answers.day, self.iterations
+        )?;
+        write!(
+            f,
+            "{:<8}{:>14}{:>14}{:>14}{:>14}",
+            "phase", "min", "median", "mean", "stddev"
+        )?;
+        for (name, stats) in [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ] {
+            write!(
+                f,
+                "\n{:<8}{:>14}{:>14}{:>14}{:>14}",
+                name,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.stddev)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Solves `input` repeatedly until `budget` is used up. Every iteration parses
+/// from scratch, so state kept between part1 and part2 is measured as well.
+pub fn bench(
+    solver: Solver,
+    input: &str,
+    params: &Params,
+    budget: &Budget,
+) -> Result<Bench, SolveError> {
+    let start_time = Instant::now();
+    let mut runs: Vec<Answers> = Vec::new();
+    loop {
+        runs.push(solver(input, params, &Token::unlimited())?);
+        let done = match budget {
+            Budget::Iterations(n) => runs.len() >= *n,
+            Budget::Time(t) => start_time.elapsed() >= *t,
+        };
+        if done {
+            break;
+        }
+    }
+    let samples = |phase: fn(&Answers) -> Duration| runs.iter().map(phase).collect::<Vec<_>>();
+    let samples = [
+        samples(|a| a.parse_time),
+        samples(|a| a.part1_time),
+        samples(|a| a.part2_time),
+    ];
+    Ok(Bench {
+        iterations: runs.len(),
+        answers: runs.swap_remove(0),
+        parse: Stats::new(&samples[0]),
+        part1: Stats::new(&samples[1]),
+        part2: Stats::new(&samples[2]),
+        samples,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::new(&[ms(4), ms(1), ms(3), ms(2)]);
+        assert!(stats.min == ms(1));
+        assert!(stats.median == Duration::from_micros(2500));
+        assert!(stats.mean == Duration::from_micros(2500));
+        assert!(stats.stddev.as_micros() == 1118);
+
+        let stats = Stats::new(&[ms(5), ms(1), ms(3)]);
+        assert!(stats.median == ms(3));
+        assert!(Stats::new(&[]).mean == Duration::ZERO);
+    }
+
+    #[test]
+    fn test_budget() {
+        assert!("20".parse::<Budget>() == Ok(Budget::Iterations(20)));
+        assert!("500ms".parse::<Budget>() == Ok(Budget::Time(Duration::from_millis(500))));
+        assert!("1.5s".parse::<Budget>() == Ok(Budget::Time(Duration::from_millis(1500))));
+        assert!("0".parse::<Budget>().is_err());
+        assert!("fast".parse::<Budget>().is_err());
+        assert!("xms".parse::<Budget>().is_err());
+        assert!(parse_duration("250ms") == Some(Duration::from_millis(250)));
+        assert!(parse_duration("-1s").is_none());
+        assert!(parse_duration("5").is_none());
+    }
+
+    #[test]
+    fn test_bench() {
+        let solver: Solver = crate::solution::solve::<crate::day01::Day01>;
+        let input = "3   4\n4   3\n";
+        let bench =
+            bench(solver, input, &Params::new(), &Budget::Iterations(3)).expect("Invalid input");
+        assert!(bench.iterations == 3);
+        assert!(bench.answers.part1 == "0" && bench.answers.part2 == "7");
+        assert!(bench.parse.min <= bench.parse.median);
+        assert!(bench.samples[2].len() == 3 && bench.samples[2].contains(&bench.part2.min));
+    }
+}
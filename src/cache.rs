@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::params::Params;
+use crate::parse::{parse_in_line, ParseError};
+use crate::solution::{Answers, DAYS};
+
+/// Identifies an input together with the parameter overrides it was solved
+/// with, FNV-1a so the value stays the same between builds.
+pub fn input_hash(input: &str, params: &Params) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut feed = |bytes: &[u8]| {
+        for b in bytes {
+            hash = (hash ^ *b as u64).wrapping_mul(0x100000001b3);
+        }
+    };
+    feed(input.as_bytes());
+    for (name, value) in params.entries() {
+        // the separator keeps the overrides apart from the input and each other
+        feed(&[0]);
+        feed(name.as_bytes());
+        feed(&value.to_le_bytes());
+    }
+    hash
+}
+
+/// Hash of the crate sources this binary was built from, see `build.rs`.
+pub const BUILD_ID: &str = env!("AOC_BUILD_ID");
+
+/// Below `target`, which `cargo clean` removes along with the builds.
+pub fn default_cache_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target/aoc-cache.txt")
+}
+
+/// Answers of earlier runs, keyed by day, part and `input_hash`.
+///
+/// The file has one `DAY PART BUILD HASH ANSWER` entry per line, where `BUILD`
+/// is the `BUILD_ID` of the binary that found the answer. Entries of any other
+/// build are dropped when the cache is read, so any change to the sources
+/// solves the days again.
+#[derive(Debug, Default)]
+pub struct Cache {
+    path: PathBuf,
+    entries: Mutex<HashMap<(u32, u32, u64), String>>,
+    recompute: bool,
+}
+
+impl Cache {
+    /// An empty cache that `save` writes to `path`.
+    pub fn new(path: &Path) -> Cache {
+        Cache {
+            path: path.to_path_buf(),
+            ..Cache::default()
+        }
+    }
+
+    /// `#` starts a comment line.
+    pub fn parse(path: &Path, input: &str) -> Result<Cache, ParseError> {
+        let ret = Cache::new(path);
+        let mut entries = ret.entries.lock().expect("Poisoned cache");
+        for (y, line) in input.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.splitn(5, ' ').collect::<Vec<&str>>();
+            let [day, part, build, hash, answer] = fields[..] else {
+                return Err(ParseError::new(y, line.len(), "DAY PART BUILD HASH ANSWER"));
+            };
+            let day: u32 = parse_in_line(y, line, day, "a day")?;
+            let part = match parse_in_line(y, line, part, "a part")? {
+                part @ 1..=2 => part,
+                _ => return Err(ParseError::in_line(y, line, part, "part 1 or 2")),
+            };
+            let Ok(hash) = u64::from_str_radix(hash, 16) else {
+                return Err(ParseError::in_line(y, line, hash, "a hexadecimal hash"));
+            };
+            if (1..=DAYS.len() as u32).contains(&day) && build == BUILD_ID {
+                entries.insert((day, part, hash), answer.to_string());
+            }
+        }
+        drop(entries);
+        Ok(ret)
+    }
+
+    /// Reads the cache at `path`, which is empty when there is no file yet. A
+    /// file that does not parse is an error, callers may start over with `new`.
+    pub fn read(path: &Path) -> Result<Cache, String> {
+        let input = match fs::read_to_string(path) {
+            Ok(input) => input,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Cache::new(path)),
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        };
+        Cache::parse(path, &input).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Writes all entries back to the path the cache was read from.
+    pub fn save(&self) -> Result<(), String> {
+        let error = |e: std::io::Error| format!("{}: {}", self.path.display(), e);
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(error)?;
+        }
+        fs::write(&self.path, format!("{}\n", self)).map_err(error)
+    }
+
+    /// Makes `get` find nothing, so every day is solved again and its entries
+    /// are replaced.
+    pub fn set_recompute(&mut self, recompute: bool) {
+        self.recompute = recompute;
+    }
+
+    /// Both answers of `day` for the input with `hash`, if the current build
+    /// found them before.
+    pub fn get(&self, day: u32, hash: u64) -> Option<(String, String)> {
+        if self.recompute {
+            return None;
+        }
+        let entries = self.entries.lock().expect("Poisoned cache");
+        let answer = |part| entries.get(&(day, part, hash)).cloned();
+        Some((answer(1)?, answer(2)?))
+    }
+
+    /// Remembers `answers` for the input with `hash`, answers spanning several
+    /// lines are not kept.
+    pub fn insert(&self, answers: &Answers, hash: u64) {
+        if !(1..=DAYS.len() as u32).contains(&answers.day) {
+            return;
+        }
+        let mut entries = self.entries.lock().expect("Poisoned cache");
+        for (part, answer) in [(1, &answers.part1), (2, &answers.part2)] {
+            let key = (answers.day, part, hash);
+            if answer.contains('\n') {
+                entries.remove(&key);
+                continue;
+            }
+            entries.insert(key, answer.clone());
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().expect("Poisoned cache").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Display for Cache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entries = self.entries.lock().expect("Poisoned cache");
+        let mut keys = entries.keys().collect::<Vec<&(u32, u32, u64)>>();
+        keys.sort();
+        write!(f, "# DAY PART BUILD HASH ANSWER, written by aoc run")?;
+        for key in keys {
+            let (day, part, hash) = key;
+            write!(
+                f,
+                "\n{} {} {} {:016x} {}",
+                day, part, BUILD_ID, hash, entries[key]
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    fn answers(day: u32, part1: &str, part2: &str) -> Answers {
+        Answers {
+            day,
+            part1: part1.to_string(),
+            part2: part2.to_string(),
+            parse_time: Duration::ZERO,
+            part1_time: Duration::ZERO,
+            part2_time: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_input_hash() {
+        let mut params = Params::new();
+        let plain = input_hash("1 2\n3 4", &params);
+        assert!(plain == input_hash("1 2\n3 4", &Params::new()));
+        assert!(plain != input_hash("1 2\n3 5", &params));
+        params.set("width", 11);
+        assert!(plain != input_hash("1 2\n3 4", &params));
+        // the FNV-1a test vector, the hash must not change between builds
+        assert!(input_hash("a", &Params::new()) == 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn test_cache() {
+        let path = Path::new("cache.txt");
+        let cache = Cache::new(path);
+        cache.insert(&answers(17, "3,5,0", "42"), 0xabc);
+        cache.insert(&answers(25, "3287", ""), 0xabc);
+        assert!(cache.get(17, 0xabc) == Some(("3,5,0".to_string(), "42".to_string())));
+        assert!(cache.get(17, 0xabd).is_none());
+        assert!(cache.get(25, 0xabc) == Some(("3287".to_string(), String::new())));
+
+        let mut parsed = Cache::parse(path, &cache.to_string()).expect("Invalid cache");
+        assert!(parsed.len() == 4);
+        assert!(parsed.get(25, 0xabc) == Some(("3287".to_string(), String::new())));
+        parsed.set_recompute(true);
+        assert!(parsed.get(25, 0xabc).is_none());
+
+        // a different build is evicted, as are days that do not exist
+        let current = format!("17 1 {} 0abc 1", BUILD_ID);
+        assert!(Cache::parse(path, &current).expect("Invalid cache").len() == 1);
+        let stale = format!("17 1 0 0abc 1\n26 1 {} 0abc 1", BUILD_ID);
+        assert!(Cache::parse(path, &stale)
+            .expect("Invalid cache")
+            .is_empty());
+        let err = Cache::parse(path, "17 1 0 xyz 1").err();
+        assert!(err == Some(ParseError::new(0, 7, "a hexadecimal hash")));
+        assert!(Cache::parse(path, "17 3 0 0abc 1").is_err());
+        // the older format with a version reads the version as the build
+        let older = format!("17 1 1 {} 0abc 1", BUILD_ID);
+        assert!(Cache::parse(path, &older)
+            .expect("Invalid cache")
+            .is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Lets a solver know when to stop: when `cancel` was called on any clone or
+/// the time budget is used up. Solvers with loops that may run for very long
+/// check `expired` and answer `None`, the run is then reported as given up.
+#[derive(Debug, Clone)]
+pub struct Token {
+    cancelled: Arc<AtomicBool>,
+    /// Set by `expired`, so a solver that stopped is told apart from one that
+    /// finished just after the deadline.
+    gave_up: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl Token {
+    /// A token that only expires when it is cancelled.
+    pub fn unlimited() -> Token {
+        Token {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            gave_up: Arc::new(AtomicBool::new(false)),
+            deadline: None,
+        }
+    }
+
+    /// A token that expires `budget` from now.
+    pub fn with_budget(budget: Duration) -> Token {
+        Token {
+            deadline: Instant::now().checked_add(budget),
+            ..Token::unlimited()
+        }
+    }
+
+    /// Asks the solvers holding a clone to stop, from any thread.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the solver should stop now. Returning `true` records that it
+    /// gave up.
+    pub fn expired(&self) -> bool {
+        let expired = self.cancelled.load(Ordering::Relaxed)
+            || self.deadline.is_some_and(|d| Instant::now() >= d);
+        if expired {
+            self.gave_up.store(true, Ordering::Relaxed);
+        }
+        expired
+    }
+
+    /// Whether a solver saw `expired` return `true`, its answer is meaningless then.
+    pub fn gave_up(&self) -> bool {
+        self.gave_up.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for Token {
+    fn default() -> Token {
+        Token::unlimited()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_cancel() {
+        let token = Token::unlimited();
+        assert!(!token.expired() && !token.gave_up());
+        let clone = token.clone();
+        thread::spawn(move || clone.cancel())
+            .join()
+            .expect("Thread failed");
+        assert!(!token.gave_up());
+        assert!(token.expired() && token.gave_up());
+    }
+
+    #[test]
+    fn test_budget() {
+        let token = Token::with_budget(Duration::ZERO);
+        assert!(token.expired());
+        let token = Token::with_budget(Duration::from_secs(3600));
+        assert!(!token.expired());
+        assert!(!Token::with_budget(Duration::MAX).expired());
+    }
+}
@@ -0,0 +1,58 @@
+use crate::input::Input;
+use crate::parse::{parse_in_line, ParseError};
+use crate::solution::Solution;
+
+pub struct Day01 {
+    first: Vec<i64>,
+    second: Vec<i64>,
+}
+
+impl Day01 {
+    /// Both location lists, sorted.
+    pub fn lists(&self) -> (&[i64], &[i64]) {
+        (&self.first, &self.second)
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day01, ParseError> {
+        let input = Input::new(input);
+        let mut first: Vec<i64> = Vec::new();
+        let mut second: Vec<i64> = Vec::new();
+        for (y, tmp) in input.lines().enumerate() {
+            let (Some(idx), Some(last_idx)) = (tmp.find(' '), tmp.rfind(' ')) else {
+                return Err(ParseError::new(
+                    y,
+                    tmp.len(),
+                    "two numbers separated by spaces",
+                ));
+            };
+            first.push(parse_in_line(y, tmp, &tmp[..idx], "a number")?);
+            second.push(parse_in_line(y, tmp, &tmp[last_idx + 1..], "a number")?);
+        }
+        first.sort();
+        second.sort();
+        Ok(Day01 { first, second })
+    }
+
+    fn part1(&mut self) -> i64 {
+        let mut distance: i64 = 0;
+        for (a, b) in self.first.iter().zip(self.second.iter()) {
+            distance += (a - b).abs();
+        }
+        distance
+    }
+
+    fn part2(&mut self) -> i64 {
+        let mut similarity: i64 = 0;
+        for a in self.first.iter() {
+            let found: i64 = self.second.iter().filter(|&n| n == a).count() as i64;
+            similarity += a * found;
+        }
+        similarity
+    }
+}
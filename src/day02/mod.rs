@@ -0,0 +1,80 @@
+use crate::input::Input;
+use crate::parse::{parse_in_line, ParseError};
+use crate::solution::Solution;
+
+/// Index of the first level that makes a report unsafe, `None` if it is safe.
+pub fn check(numbers: &[i64]) -> Option<usize> {
+    let mut prev_num: Option<i64> = None;
+    let mut prev_diff: Option<i64> = None;
+    for (i, num) in numbers.iter().enumerate() {
+        if let Some(prev) = prev_num {
+            let diff = prev - num;
+            if diff == 0 || diff.abs() > 3 {
+                return Some(i);
+            }
+            if let Some(prev_diff) = prev_diff {
+                if prev_diff > 0 && diff < 0 || prev_diff < 0 && diff > 0 {
+                    return Some(i);
+                }
+            }
+            prev_diff = Some(diff);
+        }
+        prev_num = Some(*num);
+    }
+    return None;
+}
+
+pub struct Day02 {
+    reports: Vec<Vec<i64>>,
+}
+
+impl Day02 {
+    pub fn reports(&self) -> &[Vec<i64>] {
+        &self.reports
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day02, ParseError> {
+        let input = Input::new(input);
+        let mut reports: Vec<Vec<i64>> = Vec::new();
+        for (y, line) in input.lines().enumerate() {
+            let mut tmp: Vec<i64> = Vec::new();
+            for part in line.split(' ') {
+                tmp.push(parse_in_line(y, line, part, "a level")?);
+            }
+            reports.push(tmp);
+        }
+        Ok(Day02 { reports })
+    }
+
+    fn part1(&mut self) -> i64 {
+        self.reports
+            .iter()
+            .filter(|numbers| check(numbers).is_none())
+            .count() as i64
+    }
+
+    fn part2(&mut self) -> i64 {
+        let mut safe: i64 = 0;
+        for numbers in self.reports.iter() {
+            if check(numbers).is_none() {
+                safe += 1;
+                continue;
+            }
+            for i in 0..numbers.len() {
+                let mut cpy = numbers.clone();
+                cpy.remove(i);
+                if check(&cpy).is_none() {
+                    safe += 1;
+                    break;
+                }
+            }
+        }
+        safe
+    }
+}
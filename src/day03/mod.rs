@@ -0,0 +1,117 @@
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+const MULT: &str = "mul(";
+const DO: &str = "do()";
+const DONT: &str = "don't()";
+
+/// An instruction found in the corrupted memory.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Command {
+    Mul(i64, i64),
+    Do,
+    Dont,
+}
+
+/// The instructions of a corrupted memory, everything else is skipped.
+pub struct Tokenizer<'a> {
+    data: &'a str,
+    index: usize,
+}
+
+impl Tokenizer<'_> {
+    pub fn new(data: &str) -> Tokenizer<'_> {
+        Tokenizer { data, index: 0 }
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = Command;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.data.len() {
+            if self.data[self.index..].starts_with(MULT) {
+                self.index += MULT.len();
+                let mut extract_char = |end: char| {
+                    for i in 0..4 {
+                        let chr = self.data.chars().nth(self.index + i).unwrap();
+                        if chr.is_ascii_digit() {
+                        } else if chr == end {
+                            if i > 0 {
+                                let ret = self.data[self.index..self.index + i].parse::<i64>().ok();
+                                self.index += i + 1;
+                                return ret;
+                            }
+                            break;
+                        } else {
+                            break;
+                        }
+                    }
+                    return None;
+                };
+                let a: Option<i64> = extract_char(',');
+                let b: Option<i64> = extract_char(')');
+                if a.is_some() && b.is_some() {
+                    return Some(Command::Mul(a?, b?));
+                }
+            } else if self.data[self.index..].starts_with(DO) {
+                self.index += DO.len();
+                return Some(Command::Do);
+            } else if self.data[self.index..].starts_with(DONT) {
+                self.index += DONT.len();
+                return Some(Command::Dont);
+            }
+            self.index += 1;
+        }
+        return None;
+    }
+}
+
+pub struct Day03 {
+    message: String,
+}
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day03, ParseError> {
+        let input = Input::new(input);
+        Ok(Day03 {
+            message: input.as_str().to_string(),
+        })
+    }
+
+    fn part1(&mut self) -> i64 {
+        let mut sum: i64 = 0;
+        for t in Tokenizer::new(&self.message) {
+            if let Command::Mul(a, b) = t {
+                sum += a * b;
+            }
+        }
+        sum
+    }
+
+    fn part2(&mut self) -> i64 {
+        let mut sum: i64 = 0;
+        let mut is_active = true;
+        for t in Tokenizer::new(&self.message) {
+            match t {
+                Command::Mul(a, b) => {
+                    if is_active {
+                        sum += a * b;
+                    }
+                }
+                Command::Do => {
+                    is_active = true;
+                }
+                Command::Dont => {
+                    is_active = false;
+                }
+            }
+        }
+        sum
+    }
+}
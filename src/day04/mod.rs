@@ -0,0 +1,83 @@
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::utils::{Direction, Grid, PointT};
+
+const WORD: &str = "XMAS";
+
+pub struct Day04 {
+    grid: Grid<char>,
+}
+
+impl Day04 {
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    fn check_char(&self, x: i64, y: i64, chr: Option<char>) -> bool {
+        return self.grid.get(&PointT { x, y }).copied() == chr;
+    }
+
+    /// Number of times XMAS starts at `x`, `y` in any direction.
+    pub fn check_word(&self, x: i64, y: i64) -> i64 {
+        let mut ret = 0;
+        'dirs: for dir in Direction::ALL {
+            let PointT { x: dx, y: dy } = dir.delta::<i64>();
+            for i in 0..WORD.len() as i64 {
+                if !self.check_char(x + dx * i, y + dy * i, WORD.chars().nth(i as usize)) {
+                    continue 'dirs;
+                }
+            }
+            ret += 1;
+        }
+        return ret;
+    }
+
+    /// Whether two MAS cross in an X at `x`, `y`.
+    pub fn check_mas(&self, x: i64, y: i64) -> bool {
+        return self.check_char(x, y, Some('A'))
+            && (self.check_char(x + 1, y + 1, Some('M'))
+                && self.check_char(x - 1, y - 1, Some('S'))
+                || self.check_char(x + 1, y + 1, Some('S'))
+                    && self.check_char(x - 1, y - 1, Some('M')))
+            && (self.check_char(x + 1, y - 1, Some('M'))
+                && self.check_char(x - 1, y + 1, Some('S'))
+                || self.check_char(x + 1, y - 1, Some('S'))
+                    && self.check_char(x - 1, y + 1, Some('M')));
+    }
+}
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day04, ParseError> {
+        let input = Input::new(input);
+        Ok(Day04 {
+            grid: Grid::parse_chars(input.as_str())?,
+        })
+    }
+
+    fn part1(&mut self) -> i64 {
+        let mut part1: i64 = 0;
+        for y in 0..self.grid.height() as i64 {
+            for x in 0..self.grid.width() as i64 {
+                part1 += self.check_word(x, y);
+            }
+        }
+        part1
+    }
+
+    fn part2(&mut self) -> i64 {
+        let mut part2: i64 = 0;
+        for y in 0..self.grid.height() as i64 {
+            for x in 0..self.grid.width() as i64 {
+                if self.check_mas(x, y) {
+                    part2 += 1;
+                }
+            }
+        }
+        part2
+    }
+}
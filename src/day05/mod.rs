@@ -0,0 +1,114 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::input::Input;
+use crate::parse::{parse_in_line, ParseError};
+use crate::solution::Solution;
+
+pub struct Day05 {
+    rules: HashMap<i64, HashSet<i64>>,
+    pages: Vec<Vec<i64>>,
+}
+
+impl Day05 {
+    /// For each page the pages that have to come after it.
+    pub fn rules(&self) -> &HashMap<i64, HashSet<i64>> {
+        &self.rules
+    }
+
+    pub fn updates(&self) -> &[Vec<i64>] {
+        &self.pages
+    }
+
+    /// The middle page of an update in the right order, `None` otherwise.
+    pub fn check_part1(&self, page: &[i64]) -> Option<i64> {
+        (!page.is_empty()).then_some(())?;
+        let mut before: Vec<i64> = Vec::new();
+        for num in page {
+            if let Some(set) = self.rules.get(num) {
+                for r in set {
+                    if before.contains(r) {
+                        return None;
+                    }
+                }
+            }
+            before.push(*num);
+        }
+        return page.get(page.len() / 2).cloned();
+    }
+
+    /// The update in the order of the rules.
+    pub fn fix_part2(&self, page: &[i64]) -> Vec<i64> {
+        let mut ret: Vec<i64> = Vec::new();
+        for num in page {
+            let mut idx = ret.len();
+            if let Some(rule_set) = self.rules.get(num) {
+                for r in rule_set {
+                    idx = cmp::min(ret.iter().position(|e| e == r).unwrap_or(idx), idx);
+                }
+            }
+            ret.insert(idx, *num);
+        }
+        return ret;
+    }
+}
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day05, ParseError> {
+        let mut rules: HashMap<i64, HashSet<i64>> = HashMap::new();
+        let mut pages: Vec<Vec<i64>> = Vec::new();
+        let input = Input::new(input);
+        let sections = input.sections();
+        let (rules_section, updates) = match sections[..] {
+            [] => return Ok(Day05 { rules, pages }),
+            [rules_section] => (rules_section, None),
+            [rules_section, updates] => (rules_section, Some(updates)),
+            [_, _, extra, ..] => {
+                return Err(ParseError::new(extra.line_idx, 0, "the end of the input"))
+            }
+        };
+        for (y, line) in rules_section.numbered_lines() {
+            let Some((a, b)) = line.split_once('|') else {
+                return Err(ParseError::new(y, line.len(), "a rule like 47|53"));
+            };
+            rules
+                .entry(parse_in_line(y, line, a, "a page number")?)
+                .or_default()
+                .insert(parse_in_line(y, line, b, "a page number")?);
+        }
+        for (y, line) in updates.iter().flat_map(|u| u.numbered_lines()) {
+            let tmp = line
+                .split(',')
+                .map(|n| parse_in_line(y, line, n, "a page number"))
+                .collect::<Result<Vec<i64>, ParseError>>()?;
+            pages.push(tmp);
+        }
+        Ok(Day05 { rules, pages })
+    }
+
+    fn part1(&mut self) -> i64 {
+        self.pages
+            .iter()
+            .filter_map(|page| self.check_part1(page))
+            .sum()
+    }
+
+    fn part2(&mut self) -> i64 {
+        let mut part2: i64 = 0;
+        for page in self.pages.iter() {
+            if self.check_part1(page).is_none() {
+                let fixed = self.fix_part2(page);
+                match self.check_part1(&fixed) {
+                    Some(num) => part2 += num,
+                    None => panic!("Couldn't fix page order?"),
+                }
+            }
+        }
+        part2
+    }
+}
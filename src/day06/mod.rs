@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::collections::{hash_map::Entry, HashMap};
+
+use crate::input::Input;
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::render::{Cell, Colour, Frame, Render};
+use crate::solution::Solution;
+use crate::utils::{Direction, Grid, PointT};
+
+const GUARD: char = '^';
+const CLEAR: char = '.';
+const OBSTACLE: char = '#';
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct Collision {
+    x: i32,
+    y: i32,
+    dir: Direction,
+}
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SimulationResult {
+    Leave,
+    Looping,
+}
+
+/// The guard walking through the lab, the grid is borrowed unless it is changed.
+pub struct Simulation<'a> {
+    x: i32,
+    y: i32,
+    dir: Direction,
+    grid: Cow<'a, Grid<char>>,
+    visited: HashSet<(i32, i32)>,
+    collisions: HashMap<Collision, Option<()>>,
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(start: &PointT<i32>, grid: Cow<'a, Grid<char>>) -> Simulation<'a> {
+        return Simulation {
+            x: start.x,
+            y: start.y,
+            dir: Direction::North,
+            grid,
+            visited: HashSet::new(),
+            collisions: HashMap::new(),
+        };
+    }
+    fn turn(&mut self) {
+        self.dir = self.dir.turn_right();
+    }
+    /// Moves or turns the guard once, `Some` when the walk is over.
+    pub fn step(&mut self) -> Option<SimulationResult> {
+        self.visited.insert((self.x, self.y));
+        let PointT { x: dx, y: dy } = self.dir.delta::<i32>();
+        let tx = self.x + dx;
+        let ty = self.y + dy;
+        match self.grid.get(&PointT { x: tx, y: ty }) {
+            Some(c) => {
+                if *c == OBSTACLE {
+                    let coll = Collision {
+                        x: tx,
+                        y: ty,
+                        dir: self.dir,
+                    };
+                    match self.collisions.entry(coll) {
+                        Entry::Occupied(_) => return Some(SimulationResult::Looping),
+                        Entry::Vacant(v) => v.insert(Some(())),
+                    };
+                    self.turn();
+                    return None;
+                }
+            }
+            None => return Some(SimulationResult::Leave),
+        }
+        self.x = tx;
+        self.y = ty;
+        return None;
+    }
+
+    /// Steps until the guard leaves or walks in a loop.
+    pub fn simulate(&mut self) -> SimulationResult {
+        loop {
+            if let Some(result) = self.step() {
+                return result;
+            }
+        }
+    }
+
+    /// All tiles the guard stood on so far.
+    pub fn visited(&self) -> &HashSet<(i32, i32)> {
+        &self.visited
+    }
+}
+
+pub struct Day06 {
+    grid: Grid<char>,
+    start: PointT<i32>,
+}
+
+impl Day06 {
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    pub fn start(&self) -> PointT<i32> {
+        self.start
+    }
+
+    /// The tiles visited by the guard, panics if the guard never leaves.
+    pub fn walk(&self) -> HashSet<(i32, i32)> {
+        let mut solver = Simulation::new(&self.start, Cow::Borrowed(&self.grid));
+        match solver.simulate() {
+            SimulationResult::Leave => {}
+            SimulationResult::Looping => panic!("Part1 did not return with LEAVE"),
+        }
+        return solver.visited;
+    }
+}
+
+/// The guard walking out of the lab, the last frame shows the finished walk.
+struct GuardWalk {
+    sim: Simulation<'static>,
+    result: Option<SimulationResult>,
+}
+
+impl Render for GuardWalk {
+    fn frame(&self) -> Frame {
+        let sim = &self.sim;
+        let mut frame = Frame::new(&sim.grid, |c| match *c {
+            OBSTACLE => Cell::new(OBSTACLE, Colour::Grey),
+            _ => Cell::plain(CLEAR),
+        });
+        let visited = sim.visited.iter().map(|&(x, y)| PointT { x, y });
+        frame.overlay(visited, Cell::new('X', Colour::Yellow));
+        let guard = sim.dir.glyph().expect("The guard only walks straight");
+        frame.set(
+            &PointT { x: sim.x, y: sim.y },
+            Cell::new(guard, Colour::Red),
+        );
+        let caption = format!("{} positions visited", sim.visited.len());
+        match self.result {
+            Some(SimulationResult::Looping) => frame.with_caption(caption + ", stuck in a loop"),
+            _ => frame.with_caption(caption),
+        }
+    }
+
+    fn advance(&mut self) -> bool {
+        if self.result.is_some() {
+            return false;
+        }
+        self.result = self.sim.step();
+        true
+    }
+}
+
+pub fn animation(input: &str, params: &Params) -> Result<Box<dyn Render>, ParseError> {
+    let day = Day06::parse_with(input, params)?;
+    Ok(Box::new(GuardWalk {
+        sim: Simulation::new(&day.start, Cow::Owned(day.grid)),
+        result: None,
+    }))
+}
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day06, ParseError> {
+        let input = Input::new(input);
+        let grid = Grid::parse(input.as_str(), "'.', '#' or '^'", |c| {
+            [CLEAR, OBSTACLE, GUARD].contains(&c).then_some(c)
+        })?;
+        let guards = grid
+            .positions::<i32, _>(|c| *c == GUARD)
+            .collect::<Vec<_>>();
+        match guards[..] {
+            [] => Err(ParseError::at_end(input.as_str(), "a guard '^'")),
+            [ref start] => Ok(Day06 {
+                start: *start,
+                grid,
+            }),
+            [_, ref p, ..] => Err(ParseError::new(
+                p.y as usize,
+                p.x as usize,
+                "a single guard '^'",
+            )),
+        }
+    }
+
+    fn part1(&mut self) -> usize {
+        self.walk().len()
+    }
+
+    fn part2(&mut self) -> i64 {
+        let mut part2: i64 = 0;
+        for (x, y) in self.walk().iter() {
+            let p = PointT { x: *x, y: *y };
+            // modify the map
+            {
+                let chr = &mut self.grid[&p];
+                if *chr != CLEAR {
+                    continue;
+                }
+                *chr = OBSTACLE;
+            }
+            // solve
+            let mut solver2 = Simulation::new(&self.start, Cow::Borrowed(&self.grid));
+            if solver2.simulate() == SimulationResult::Looping {
+                part2 += 1;
+            }
+            // undo map modification
+            self.grid[&p] = CLEAR;
+        }
+        part2
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::Display;
+
+use crate::input::Input;
+use crate::parse::{parse_in_line, ParseError};
+use crate::solution::Solution;
+
+pub type Operation = fn(i64, i64) -> i64;
+
+/// One calibration equation, a test value and the numbers that should produce it.
+pub struct Solver {
+    solution: i64,
+    numbers: Vec<i64>,
+}
+
+impl Solver {
+    /// Parses line `line_idx` of the input.
+    pub fn new(line_idx: usize, line: &str) -> Result<Solver, ParseError> {
+        let Some((sol, nums)) = line.split_once(": ") else {
+            return Err(ParseError::new(line_idx, 0, "an equation like 190: 10 19"));
+        };
+        let sol = parse_in_line(line_idx, line, sol, "a test value")?;
+        let nums = nums
+            .split(' ')
+            .map(|v| parse_in_line(line_idx, line, v, "a number"))
+            .collect::<Result<Vec<i64>, ParseError>>()?;
+        if nums.len() < 2 {
+            return Err(ParseError::new(
+                line_idx,
+                line.len(),
+                "at least two numbers",
+            ));
+        }
+        return Ok(Solver {
+            solution: sol,
+            numbers: nums,
+        });
+    }
+
+    fn rec(&self, ops: &[Operation], i: usize, sum: i64) -> bool {
+        if sum > self.solution {
+            return false;
+        }
+        if i == self.numbers.len() - 1 {
+            return sum == self.solution;
+        }
+        match self.numbers.get(i + 1) {
+            Some(b) => return ops.iter().any(|v| self.rec(ops, i + 1, v(sum, *b))),
+            None => return false,
+        }
+    }
+
+    pub fn solution(&self) -> i64 {
+        self.solution
+    }
+
+    pub fn numbers(&self) -> &[i64] {
+        &self.numbers
+    }
+
+    /// Whether `ops` between the numbers, evaluated left to right, give the test value.
+    pub fn solve(&self, ops: &[Operation]) -> bool {
+        return match self.numbers.first() {
+            Some(n) => self.rec(ops, 0, *n),
+            None => false,
+        };
+    }
+}
+
+fn mult(a: i64, b: i64) -> i64 {
+    return a * b;
+}
+
+fn add(a: i64, b: i64) -> i64 {
+    return a + b;
+}
+
+fn concat(a: i64, b: i64) -> i64 {
+    let mut tmp = b;
+    let mut tmp2 = a;
+    loop {
+        tmp /= 10;
+        tmp2 *= 10;
+        if tmp == 0 {
+            break;
+        }
+    }
+    return tmp2 + b;
+}
+
+impl Display for Solver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {:?}", self.solution, self.numbers)
+    }
+}
+
+pub const PART1_OPS: [Operation; 2] = [mult, add];
+pub const PART2_OPS: [Operation; 3] = [mult, add, concat];
+
+pub struct Day07 {
+    equations: Vec<Solver>,
+}
+
+impl Day07 {
+    pub fn equations(&self) -> &[Solver] {
+        &self.equations
+    }
+}
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day07, ParseError> {
+        let input = Input::new(input);
+        Ok(Day07 {
+            equations: input
+                .lines()
+                .enumerate()
+                .map(|(y, line)| Solver::new(y, line))
+                .collect::<Result<Vec<Solver>, ParseError>>()?,
+        })
+    }
+
+    fn part1(&mut self) -> i64 {
+        self.equations
+            .iter()
+            .filter(|s| s.solve(&PART1_OPS))
+            .map(|s| s.solution)
+            .sum()
+    }
+
+    fn part2(&mut self) -> i64 {
+        self.equations
+            .iter()
+            .filter(|s| s.solve(&PART1_OPS) || s.solve(&PART2_OPS))
+            .map(|s| s.solution)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_concat() {
+        assert_eq!(concat(1, 2), 12);
+        assert_eq!(concat(1, 0), 10);
+        assert_eq!(concat(0, 1), 1);
+        assert_eq!(concat(10, 10), 1010);
+        assert_eq!(concat(1234, 1234), 12341234);
+        assert_eq!(concat(12, 1230), 121230);
+        assert_eq!(concat(2843285760, 74), 284328576074);
+        assert_eq!(concat(74, 2843285760), 742843285760);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Day07::parse("190: 10 19\n3267: 81 40 27").is_ok());
+        let err = Day07::parse("190: 10 19\n3267: 81 4x 27").err();
+        assert!(err == Some(ParseError::new(1, 9, "a number")));
+        let err = Day07::parse("190: 10 19\n3267 81 40 27").err();
+        assert!(err == Some(ParseError::new(1, 0, "an equation like 190: 10 19")));
+    }
+}
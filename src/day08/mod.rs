@@ -0,0 +1,105 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::utils::{Grid, PointT, RectT};
+
+type Point = PointT<i32>;
+type Rect = RectT<i32>;
+
+const CLEAR: char = '.';
+
+fn add(a: i32, b: i32) -> i32 {
+    return a + b;
+}
+
+fn sub(a: i32, b: i32) -> i32 {
+    return a - b;
+}
+
+const OPERATIONS: [fn(i32, i32) -> i32; 2] = [add, sub];
+
+pub struct Day08 {
+    frequencies: HashMap<char, Vec<Point>>,
+    bounds: Rect,
+}
+
+impl Day08 {
+    /// The antenna positions of each frequency.
+    pub fn frequencies(&self) -> &HashMap<char, Vec<Point>> {
+        &self.frequencies
+    }
+
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Antinodes at twice the distance of two antennas (part 1) and anywhere on
+    /// their line (part 2).
+    pub fn find_antinodes(&self) -> (HashSet<Point>, HashSet<Point>) {
+        let mut antinodes_part1: HashSet<Point> = HashSet::new();
+        let mut antinodes_part2: HashSet<Point> = HashSet::new();
+        for nodes in self.frequencies.values() {
+            for a in nodes.iter() {
+                for b in nodes.iter() {
+                    if a == b {
+                        continue;
+                    }
+                    antinodes_part2.insert(*a);
+                    antinodes_part2.insert(*b);
+                    let dx = a.x - b.x;
+                    let dy = a.y - b.y;
+
+                    for (op, item) in OPERATIONS.iter().zip([a, b]) {
+                        for i in 1.. {
+                            let p = Point {
+                                x: op(item.x, dx * i),
+                                y: op(item.y, dy * i),
+                            };
+                            if self.bounds.contains_point(&p) {
+                                if i == 1 {
+                                    antinodes_part1.insert(p);
+                                }
+                                antinodes_part2.insert(p);
+                            } else {
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        return (antinodes_part1, antinodes_part2);
+    }
+}
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Day08, ParseError> {
+        let input = Input::new(input);
+        let map = Grid::parse_chars(input.as_str())?;
+        let mut frequencies: HashMap<char, Vec<Point>> = HashMap::new();
+        for (p, c) in map.iter() {
+            if *c != CLEAR {
+                frequencies.entry(*c).or_default().push(p);
+            }
+        }
+        let bounds = map.bounds();
+        Ok(Day08 {
+            frequencies,
+            bounds,
+        })
+    }
+
+    fn part1(&mut self) -> usize {
+        self.find_antinodes().0.len()
+    }
+
+    fn part2(&mut self) -> usize {
+        self.find_antinodes().1.len()
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt::Display;
+
+use crate::event;
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+/// A file of the disk map, `initial_index` is its first block before compacting.
+pub struct File {
+    pub id: i32,
+    pub size: usize,
+    pub initial_index: usize,
+}
+
+impl Display for File {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "id={} size={}", self.id, self.size)
+    }
+}
+
+/// Sum of the block positions times the file IDs, free blocks are skipped.
+pub fn checksum(disk: &[Option<&File>]) -> i64 {
+    let mut ret: i64 = 0;
+    for (i, slot) in disk.iter().enumerate() {
+        if let Some(file) = slot {
+            ret += i as i64 * file.id as i64;
+        }
+    }
+    return ret;
+}
+
+/// The disk like the example shows it, the IDs of the blocks and `.` for free ones.
+fn disk_map(disk: &[Option<&File>]) -> String {
+    let mut tmp = String::new();
+    for i in disk.iter() {
+        match i {
+            Some(file) => tmp.push_str(file.id.to_string().as_str()),
+            None => tmp.push('.'),
+        }
+    }
+    tmp
+}
+
+fn part1(mut disk: Vec<Option<&File>>) -> i64 {
+    let mut front_idx: usize = 0;
+    let mut rear_idx = disk.len() - 1;
+    while front_idx < rear_idx {
+        let Some(file) = disk[rear_idx] else {
+            rear_idx -= 1;
+            continue;
+        };
+        for i in 0..file.size {
+            while front_idx < rear_idx - i {
+                if disk[front_idx].is_none() {
+                    break;
+                } else {
+                    front_idx += 1
+                }
+            }
+            if disk[front_idx].is_some() {
+                break;
+            }
+            disk[front_idx] = disk[rear_idx - i];
+            disk[rear_idx - i] = None;
+            front_idx += 1;
+        }
+    }
+    event!(Trace, "compacted blocks {}", disk_map(&disk));
+    return checksum(&disk);
+}
+
+fn find_space(
+    find_start: &mut usize,
+    disk: &[Option<&File>],
+    size: usize,
+    end: usize,
+) -> Option<usize> {
+    let mut streak = 0;
+    let mut only_used = true;
+    for (i, slot) in disk.iter().enumerate().take(end).skip(*find_start) {
+        if slot.is_some() {
+            if only_used {
+                *find_start = i;
+            } else {
+                streak = 0;
+            }
+        } else {
+            streak += 1;
+            only_used = false;
+            if streak == size {
+                return Some(i - (size - 1));
+            }
+        }
+    }
+    return None;
+}
+
+fn part2(mut disk: Vec<Option<&File>>, files: &[File]) -> i64 {
+    let mut find_start: usize = 0;
+
+    for file in files.iter().rev() {
+        let idx = find_space(&mut find_start, &disk, file.size, file.initial_index);
+        if let Some(idx) = idx {
+            event!(Debug, "moving file {} to {}", file, idx);
+            for i in 0..file.size {
+                assert!(disk[idx + i].is_none());
+                disk[idx + i] = disk[file.initial_index + i];
+                disk[file.initial_index + i] = None;
+            }
+        }
+    }
+    event!(Trace, "compacted files {}", disk_map(&disk));
+    return checksum(&disk);
+}
+
+pub struct Day09 {
+    files: Vec<File>,
+}
+
+impl Day09 {
+    pub fn files(&self) -> &[File] {
+        &self.files
+    }
+
+    /// The file of every block, `None` for free ones.
+    pub fn build_disk(&self) -> Vec<Option<&File>> {
+        let mut disk: Vec<Option<&File>> = Vec::new();
+        let last = self.files.last().unwrap();
+        disk.resize(last.initial_index + last.size, None);
+        for file in self.files.iter() {
+            for slot in disk.iter_mut().skip(file.initial_index).take(file.size) {
+                *slot = Some(file);
+            }
+        }
+        return disk;
+    }
+}
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day09, ParseError> {
+        let input = Input::new(input);
+        let mut files: Vec<File> = Vec::new();
+        let mut position: usize = 0;
+        for (i, c) in input.as_str().chars().enumerate() {
+            let Some(size) = c.to_digit(10) else {
+                return Err(ParseError::new(0, i, "a digit"));
+            };
+            if (i & 1) == 0 {
+                let file = File {
+                    id: (i / 2) as i32,
+                    size: size as usize,
+                    initial_index: position,
+                };
+                position += file.size;
+                files.push(file);
+            } else {
+                position += size as usize;
+            }
+        }
+        if files.is_empty() {
+            return Err(ParseError::new(0, 0, "a disk map"));
+        }
+        Ok(Day09 { files })
+    }
+
+    fn part1(&mut self) -> i64 {
+        part1(self.build_disk())
+    }
+
+    fn part2(&mut self) -> i64 {
+        part2(self.build_disk(), &self.files)
+    }
+}
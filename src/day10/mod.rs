@@ -0,0 +1,76 @@
+use std::collections::HashSet;
+
+use crate::event;
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::utils::{Grid, PointT};
+
+const TRAIL_HEAD: u8 = 0;
+const TRAIL_END: u8 = 9;
+const IMPASSABLE: char = '.';
+
+fn walk(p: &PointT<i32>, map: &Grid<u8>) -> Vec<PointT<i32>> {
+    let mut ret = Vec::new();
+    let height = map[p];
+    if height == TRAIL_END {
+        event!(Trace, "trail end at {}", p);
+        ret.push(*p);
+        return ret;
+    }
+    for target in map.neighbours(p) {
+        if map[&target] == height + 1 {
+            ret.extend(walk(&target, map));
+        }
+    }
+    return ret;
+}
+
+pub struct Day10 {
+    map: Grid<u8>,
+}
+
+impl Day10 {
+    /// Heights of the map, impassable tiles are `u8::MAX`.
+    pub fn map(&self) -> &Grid<u8> {
+        &self.map
+    }
+
+    /// For each trail head the trail end of every hiking trail starting there.
+    pub fn trails(&self) -> Vec<Vec<PointT<i32>>> {
+        return self
+            .map
+            .positions(|height| *height == TRAIL_HEAD)
+            .map(|p| walk(&p, &self.map))
+            .collect();
+    }
+}
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day10, ParseError> {
+        let input = Input::new(input);
+        let map = Grid::parse(input.as_str(), "a height or '.'", |c| {
+            match c.to_digit(10) {
+                Some(number) => Some(number as u8),
+                None if c == IMPASSABLE => Some(u8::MAX),
+                None => None,
+            }
+        })?;
+        Ok(Day10 { map })
+    }
+
+    fn part1(&mut self) -> i64 {
+        self.trails()
+            .iter()
+            .map(|found| found.iter().collect::<HashSet<_>>().len() as i64)
+            .sum()
+    }
+
+    fn part2(&mut self) -> i64 {
+        self.trails().iter().map(|found| found.len() as i64).sum()
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use crate::input::Input;
+use crate::params::{Param, Params};
+use crate::parse::{parse_in_line, ParseError};
+use crate::solution::Solution;
+
+type StoneMap = HashMap<i64, Stone>;
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct Stone {
+    value: i64,
+    count: i64,
+}
+
+fn split_value(value: i64) -> Option<(i64, i64)> {
+    let mut tmp = value;
+    let mut digits = 0;
+    loop {
+        tmp /= 10;
+        digits += 1;
+        if tmp == 0 {
+            break;
+        }
+    }
+    if digits & 1 == 1 {
+        return None;
+    }
+    let half: i64 = 10_i64.pow(digits / 2);
+    return Some((value / half, value % half));
+}
+
+impl Stone {
+    fn simulate(mut self, out_dict: &mut StoneMap) {
+        if self.value == 0 {
+            self.value = 1;
+        } else if let Some(half) = split_value(self.value) {
+            Stone {
+                value: half.0,
+                count: self.count,
+            }
+            .add_to(out_dict);
+            self.value = half.1;
+        } else {
+            self.value *= 2024;
+        }
+        self.add_to(out_dict);
+    }
+    fn add_to(self, dict: &mut StoneMap) {
+        match dict.entry(self.value) {
+            Entry::Occupied(o) => o.into_mut().count += self.count,
+            Entry::Vacant(v) => {
+                v.insert(self);
+            }
+        };
+    }
+    fn parse(s: &str) -> StoneMap {
+        let mut ret: StoneMap = HashMap::new();
+        for num in s.split(' ').filter_map(|n| n.parse::<i64>().ok()) {
+            Stone {
+                value: num,
+                count: 1,
+            }
+            .add_to(&mut ret);
+        }
+        return ret;
+    }
+    fn count(map: &StoneMap) -> i64 {
+        let mut ret: i64 = 0;
+        for s in map.values() {
+            ret += s.count;
+        }
+        return ret;
+    }
+}
+
+fn blink(mut stones: StoneMap, times: usize) -> StoneMap {
+    for _ in 0..times {
+        let mut solved: StoneMap = HashMap::new();
+        for (_, stone) in stones {
+            stone.simulate(&mut solved);
+        }
+        stones = solved;
+    }
+    return stones;
+}
+
+const PART1_BLINKS: Param = Param {
+    name: "part1_blinks",
+    default: 25,
+    min: 0,
+    max: 90,
+    help: "Blinks before part 1 counts the stones",
+};
+const PART2_BLINKS: Param = Param {
+    name: "part2_blinks",
+    default: 75,
+    min: 0,
+    max: 90,
+    help: "Blinks before part 2 counts the stones",
+};
+
+pub struct Day11 {
+    stones: StoneMap,
+    blinks: [usize; 2],
+}
+
+impl Day11 {
+    /// Number of stones after blinking `times` times.
+    pub fn count_after(&self, times: usize) -> i64 {
+        Stone::count(&blink(self.stones.clone(), times))
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Part1 = i64;
+    type Part2 = i64;
+    const PARAMS: &'static [Param] = &[PART1_BLINKS, PART2_BLINKS];
+
+    fn parse(input: &str) -> Result<Day11, ParseError> {
+        let input = Input::new(input);
+        let line = input.as_str();
+        for num in line.split(' ') {
+            parse_in_line::<i64>(0, line, num, "a number")?;
+        }
+        Ok(Day11 {
+            stones: Stone::parse(line),
+            blinks: [PART1_BLINKS.default as usize, PART2_BLINKS.default as usize],
+        })
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Day11, ParseError> {
+        let mut ret = Day11::parse(input)?;
+        ret.blinks = [PART1_BLINKS, PART2_BLINKS].map(|p| params.get(&p) as usize);
+        Ok(ret)
+    }
+
+    fn part1(&mut self) -> i64 {
+        self.count_after(self.blinks[0])
+    }
+
+    fn part2(&mut self) -> i64 {
+        self.count_after(self.blinks[1])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_parse() {
+        let stones = Stone::parse("125 17");
+        let expected = HashMap::from([
+            (
+                125,
+                Stone {
+                    value: 125,
+                    count: 1,
+                },
+            ),
+            (
+                17,
+                Stone {
+                    value: 17,
+                    count: 1,
+                },
+            ),
+        ]);
+        assert!(stones == expected);
+    }
+    #[test]
+    fn test_simulate() {
+        let mut stones = Stone::parse("125 17");
+        const EXPECTED: [&str; 6] = [
+            "253000 1 7",
+            "253 0 2024 14168",
+            "512072 1 20 24 28676032",
+            "512 72 2024 2 0 2 4 2867 6032",
+            "1036288 7 2 20 24 4048 1 4048 8096 28 67 60 32",
+            "2097446912 14168 4048 2 0 2 4 40 48 2024 40 48 80 96 2 8 6 7 6 0 3 2",
+        ];
+        for exp in EXPECTED {
+            let mut solved: StoneMap = HashMap::new();
+            for (_, stone) in stones {
+                stone.simulate(&mut solved);
+            }
+            assert!(solved == Stone::parse(exp));
+            stones = solved;
+        }
+    }
+    #[test]
+    fn test_split() {
+        let result = split_value(123);
+        assert!(result.is_none());
+        let result = split_value(1234);
+        assert!(result == Some((12, 34)));
+    }
+}
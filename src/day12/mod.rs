@@ -0,0 +1,274 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::utils::{Grid, PointT};
+
+type Map = Grid<Option<char>>;
+type Point = PointT<i64>;
+
+const OPERATIONS: [fn(i64, i64) -> i64; 2] = [|a, b| a + b, |a, b| a - b];
+
+/// A region of plots with the same plant.
+pub struct Area {
+    id: char,
+    area: i64,
+    fences: HashSet<Fence>,
+}
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct Fence {
+    inside: Point,
+    outside: Point,
+}
+
+struct Garden {
+    map: Map,
+    areas: Vec<Area>,
+}
+
+impl Display for Area {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: size={} fences={}",
+            self.id,
+            self.area,
+            self.fences.len()
+        )
+    }
+}
+impl Area {
+    pub fn plant(&self) -> char {
+        self.id
+    }
+
+    /// Number of plots.
+    pub fn area(&self) -> i64 {
+        self.area
+    }
+
+    /// Number of fence pieces around the region.
+    pub fn perimeter(&self) -> usize {
+        self.fences.len()
+    }
+}
+
+impl Fence {
+    fn new(in_: Point, out: Point) -> Fence {
+        assert!(in_.distance(&out) == 1, "Invalid coordinates");
+        Fence {
+            inside: in_,
+            outside: out,
+        }
+    }
+}
+impl Garden {
+    fn parse(input: &str) -> Result<Garden, ParseError> {
+        let input = Input::new(input);
+        Ok(Garden {
+            map: Grid::parse(input.as_str(), "a plant", |c| Some(Some(c)))?,
+            areas: Vec::new(),
+        })
+    }
+
+    fn rec_map_area(
+        &self,
+        id: char,
+        p: Point,
+        prev: Option<&Point>,
+        out_visited: &mut HashSet<Point>,
+        out_fences: &mut HashSet<Fence>,
+    ) -> i64 {
+        let mut size: i64 = 0;
+        if !out_visited.contains(&p) {
+            if self.map.get(&p) == Some(&Some(id)) {
+                out_visited.insert(p);
+                size += 1;
+                for target in p.neighbours() {
+                    size += self.rec_map_area(id, target, Some(&p), out_visited, out_fences)
+                }
+            } else if let Some(prev) = prev {
+                out_fences.insert(Fence::new(*prev, p));
+            }
+        }
+        return size;
+    }
+
+    fn build_areas(&mut self) {
+        self.areas.clear();
+        for y in 0..self.map.height() as i64 {
+            for x in 0..self.map.width() as i64 {
+                if let Some(chr) = self.map[&Point { x, y }] {
+                    let mut visited: HashSet<Point> = HashSet::new();
+                    let mut fences: HashSet<Fence> = HashSet::new();
+                    let size =
+                        self.rec_map_area(chr, Point { x, y }, None, &mut visited, &mut fences);
+                    // remove used chars from the map
+                    for point in visited {
+                        if self.map[&point] == Some(chr) {
+                            self.map[&point] = None;
+                        }
+                    }
+                    let area = Area {
+                        id: chr,
+                        area: size,
+                        fences,
+                    };
+                    self.areas.push(area);
+                }
+            }
+        }
+    }
+
+    fn get_part1(&self) -> i64 {
+        self.areas
+            .iter()
+            .fold(0, |acc, a| acc + a.area * a.fences.len() as i64)
+    }
+
+    fn get_part2(&self) -> i64 {
+        let mut ret: i64 = 0;
+        for area in self.areas.iter() {
+            let mut used: HashSet<Fence> = HashSet::new();
+            let mut num_fences = 0;
+            for fence in area.fences.iter() {
+                if !used.contains(fence) {
+                    num_fences += 1;
+                    let dx = match fence.inside.x == fence.outside.x {
+                        true => 1_i64,
+                        false => 0_i64,
+                    };
+                    let dy = match fence.inside.y == fence.outside.y {
+                        true => 1_i64,
+                        false => 0_i64,
+                    };
+                    assert!(dx + dy == 1, "Invalid fence");
+                    for op in OPERATIONS {
+                        for i in 1_i64.. {
+                            let neighbour = Fence::new(
+                                Point {
+                                    x: op(fence.inside.x, dx * i),
+                                    y: op(fence.inside.y, dy * i),
+                                },
+                                Point {
+                                    x: op(fence.outside.x, dx * i),
+                                    y: op(fence.outside.y, dy * i),
+                                },
+                            );
+                            if area.fences.contains(&neighbour) {
+                                used.insert(neighbour);
+                            } else {
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+            ret += area.area * num_fences;
+        }
+        return ret;
+    }
+}
+
+pub struct Day12 {
+    garden: Garden,
+}
+
+impl Day12 {
+    /// All regions of the garden, mapped on first use.
+    pub fn regions(&mut self) -> &[Area] {
+        if self.garden.areas.is_empty() {
+            self.garden.build_areas();
+        }
+        &self.garden.areas
+    }
+}
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day12, ParseError> {
+        Ok(Day12 {
+            garden: Garden::parse(input)?,
+        })
+    }
+
+    fn part1(&mut self) -> i64 {
+        self.regions();
+        self.garden.get_part1()
+    }
+
+    fn part2(&mut self) -> i64 {
+        self.regions();
+        self.garden.get_part2()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::*;
+    #[test]
+    fn test_small_examples() {
+        {
+            let mut garden = Garden::parse(
+                "AAAA
+BBCD
+BBCC
+EEEC",
+            )
+            .expect("Invalid input");
+            garden.build_areas();
+            assert!(garden.get_part1() == 140);
+            assert!(garden.get_part2() == 80);
+        }
+    }
+
+    #[test]
+    fn test_fully_contained() {
+        {
+            let mut garden = Garden::parse(
+                "OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO",
+            )
+            .expect("Invalid input");
+            garden.build_areas();
+            assert!(garden.get_part1() == 772);
+            assert!(garden.get_part2() == 436);
+        }
+    }
+
+    #[test]
+    fn test_mobius_fencing() {
+        {
+            let mut garden = Garden::parse(
+                "AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA",
+            )
+            .expect("Invalid input");
+            garden.build_areas();
+            assert!(garden.get_part2() == 368);
+        }
+    }
+
+    #[test]
+    fn test_dbg() {
+        let input = fs::read_to_string("src/day12/dbg.txt").unwrap();
+        let mut garden = Garden::parse(&input).expect("Invalid input");
+        garden.build_areas();
+        assert!(garden.get_part1() == 1930);
+        assert!(garden.get_part2() == 1206);
+    }
+}
@@ -0,0 +1,216 @@
+use std::fmt::Debug;
+
+use crate::input::{Input, Section};
+use crate::params::{Param, Params};
+use crate::parse::{parse_n_integers, ParseError};
+use crate::solution::Solution;
+use crate::utils::PointT;
+
+/// How much further away the prizes are in part 2.
+pub const ERROR: i64 = 10000000000000;
+
+const OFFSET: Param = Param {
+    name: "offset",
+    default: ERROR,
+    min: 0,
+    max: 1_000_000_000_000_000,
+    help: "How much further away the prizes are in part 2",
+};
+
+type Point = PointT<i64>;
+
+/// A claw machine, the buttons move the claw by `delta_a` and `delta_b`.
+pub struct Machine {
+    pub delta_a: Point,
+    pub delta_b: Point,
+    pub prize: Point,
+}
+impl Debug for Machine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let p1 = self.solve_p1();
+        let p2 = self.solve_p2();
+        write!(
+            f,
+            "A:{}, B:{} Prize:{} Part1={:?} Part2={:?}",
+            self.delta_a, self.delta_b, self.prize, p1, p2
+        )
+    }
+}
+impl Machine {
+    pub fn parse(section: &Section) -> Result<Machine, ParseError> {
+        let mut d_a: Option<Point> = None;
+        let mut d_b: Option<Point> = None;
+        let mut p: Option<Point> = None;
+
+        for (line_idx, line) in section.numbered_lines() {
+            let parse_int_pair = |value: &str| -> Result<Point, ParseError> {
+                let [x, y] = parse_n_integers(line_idx, line, value, "X and Y values")?;
+                Ok(Point { x, y })
+            };
+            let parts: Vec<&str> = line.split(':').collect();
+            match parts[..] {
+                ["Button A", value] => d_a = Some(parse_int_pair(value)?),
+                ["Button B", value] => d_b = Some(parse_int_pair(value)?),
+                ["Prize", value] => p = Some(parse_int_pair(value)?),
+                _ => return Err(ParseError::new(line_idx, 0, "Button A, Button B or Prize")),
+            };
+        }
+        return match (d_a, d_b, p) {
+            (Some(d_a), Some(d_b), Some(p)) => Ok(Machine {
+                delta_a: d_a,
+                delta_b: d_b,
+                prize: p,
+            }),
+            _ => Err(ParseError::new(
+                section.line_idx + section.text.lines().count(),
+                0,
+                "Button A, Button B and Prize lines",
+            )),
+        };
+    }
+
+    /// Tokens needed to win the prize with at most 100 presses per button.
+    pub fn solve_p1(&self) -> Option<i64> {
+        let a = self.delta_a.x;
+        let b = self.delta_b.x;
+        let c = self.prize.x;
+        let d = self.delta_a.y;
+        let e = self.delta_b.y;
+        let f = self.prize.y;
+        let top = a * f - d * c;
+        let bottom = a * e - d * b;
+        if top % bottom != 0 {
+            return None;
+        }
+        let y = top / bottom;
+        let top = c - b * y;
+        if top % a != 0 {
+            return None;
+        }
+        let x = top / a;
+        if x > 100 || y > 100 {
+            return None;
+        }
+        return Some(x * 3 + y);
+    }
+
+    /// Tokens needed to win the prize when it is `ERROR` further away.
+    pub fn solve_p2(&self) -> Option<i128> {
+        self.solve_with_offset(ERROR)
+    }
+
+    /// Tokens needed to win the prize when it is `offset` further away, with
+    /// any number of presses.
+    pub fn solve_with_offset(&self, offset: i64) -> Option<i128> {
+        let a = self.delta_a.x as i128;
+        let b = self.delta_b.x as i128;
+        let c = self.prize.x as i128 + offset as i128;
+        let d = self.delta_a.y as i128;
+        let e = self.delta_b.y as i128;
+        let f = self.prize.y as i128 + offset as i128;
+        let top = a * f - d * c;
+        let bottom = a * e - d * b;
+        if top % bottom != 0 {
+            return None;
+        }
+        let y = top / bottom;
+        let top = c - b * y;
+        if top % a != 0 {
+            return None;
+        }
+        let x = top / a;
+        return Some(x * 3 + y);
+    }
+}
+
+pub struct Day13 {
+    machines: Vec<Machine>,
+    offset: i64,
+}
+
+impl Day13 {
+    pub fn machines(&self) -> &[Machine] {
+        &self.machines
+    }
+}
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Part1 = i64;
+    type Part2 = i128;
+    const PARAMS: &'static [Param] = &[OFFSET];
+
+    fn parse(input: &str) -> Result<Day13, ParseError> {
+        let input = Input::new(input);
+        let machines = input
+            .sections()
+            .iter()
+            .map(Machine::parse)
+            .collect::<Result<Vec<Machine>, ParseError>>()?;
+        Ok(Day13 {
+            machines,
+            offset: ERROR,
+        })
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Day13, ParseError> {
+        let mut ret = Day13::parse(input)?;
+        ret.offset = params.get(&OFFSET);
+        Ok(ret)
+    }
+
+    fn part1(&mut self) -> i64 {
+        self.machines.iter().filter_map(|m| m.solve_p1()).sum()
+    }
+
+    fn part2(&mut self) -> i128 {
+        self.machines
+            .iter()
+            .filter_map(|m| m.solve_with_offset(self.offset))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_small_example() {
+        let input = r#"Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279"#;
+        let mut day = Day13::parse(input).expect("Invalid input");
+        assert!(day.machines.len() == 4);
+        assert!(day.part1() == 480);
+        assert!(day.part2() == 875318608908);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\nButton A: X+26, Y+66\nPrize: X=12748";
+        let err = Day13::parse(input).err();
+        assert!(err == Some(ParseError::new(5, 6, "X and Y values")));
+    }
+
+    #[test]
+    fn test_parse_negative() {
+        let day = Day13::parse("Button A: X+3, Y-4\nButton B: X-1, Y+2\nPrize: X=5, Y=-6")
+            .expect("Invalid input");
+        let machine = &day.machines[0];
+        assert!(machine.delta_a == Point { x: 3, y: -4 });
+        assert!(machine.delta_b == Point { x: -1, y: 2 });
+        assert!(machine.prize == Point { x: 5, y: -6 });
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+use std::{collections::HashSet, fmt::Debug};
+
+use crate::cancel::Token;
+use crate::input::Input;
+use crate::params::{Param, Params};
+use crate::parse::{ParseError, Pattern};
+use crate::render::{Cell, Colour, Frame, Render};
+use crate::solution::Solution;
+use crate::utils::{CenterLine, PointT, RectT};
+
+type Point = PointT<i64>;
+type Rect = RectT<i64>;
+
+/// The floor of the real input, the example is smaller.
+pub const BOUNDS: Rect = Rect {
+    x: 0,
+    y: 0,
+    width: 101,
+    height: 103,
+};
+
+const WIDTH: Param = Param {
+    name: "width",
+    default: BOUNDS.width,
+    min: 1,
+    max: 1000,
+    help: "Width of the floor, 11 in the example",
+};
+const HEIGHT: Param = Param {
+    name: "height",
+    default: BOUNDS.height,
+    min: 1,
+    max: 1000,
+    help: "Height of the floor, 7 in the example",
+};
+const SECONDS: Param = Param {
+    name: "seconds",
+    default: 100,
+    min: 0,
+    max: 1_000_000_000,
+    help: "Seconds the robots move before part 1 counts the quadrants",
+};
+
+pub struct Robot {
+    pub position: Point,
+    pub velocity: Point,
+}
+
+pub struct Day14 {
+    bounds: Rect,
+    robots: Vec<Robot>,
+    seconds: i64,
+}
+
+impl Debug for Robot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Position:{}, Velocity:{}", self.position, self.velocity)
+    }
+}
+impl Robot {
+    /// Parses a line like `p=0,4 v=3,-3`.
+    pub fn parse(line_idx: usize, line: &str) -> Result<Robot, ParseError> {
+        let [px, py, vx, vy] = Pattern::new("p={},{} v={},{}").parse(line_idx, line, "a number")?;
+        Ok(Robot {
+            position: Point { x: px, y: py },
+            velocity: Point { x: vx, y: vy },
+        })
+    }
+
+    /// Where the robot is after `seconds`, it wraps around the edges of `bounds`.
+    pub fn get_position(&self, seconds: i64, bounds: &Rect) -> Point {
+        return bounds.wrap(&(self.position + self.velocity * seconds));
+    }
+}
+
+impl Day14 {
+    /// Parses the robots of a floor of any size, `parse` uses `BOUNDS`.
+    pub fn parse_with_bounds(input: &str, bounds: Rect) -> Result<Day14, ParseError> {
+        let input = Input::new(input);
+        Ok(Day14 {
+            bounds,
+            robots: input
+                .lines()
+                .enumerate()
+                .map(|(y, line)| Robot::parse(y, line))
+                .collect::<Result<Vec<Robot>, ParseError>>()?,
+            seconds: SECONDS.default,
+        })
+    }
+
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    pub fn robots(&self) -> &[Robot] {
+        &self.robots
+    }
+}
+
+/// The robots moving around the floor, one frame per second.
+struct Floor {
+    day: Day14,
+    seconds: i64,
+}
+
+impl Render for Floor {
+    fn frame(&self) -> Frame {
+        let bounds = &self.day.bounds;
+        let mut counts: HashMap<Point, u32> = HashMap::new();
+        for robot in self.day.robots.iter() {
+            *counts
+                .entry(robot.get_position(self.seconds, bounds))
+                .or_default() += 1;
+        }
+        let mut frame = Frame::blank(
+            bounds.width as usize,
+            bounds.height as usize,
+            Cell::plain('.'),
+        );
+        for (p, count) in counts {
+            let glyph = char::from_digit(count, 10).unwrap_or('+');
+            frame.set(&(p - bounds.min()), Cell::new(glyph, Colour::Green));
+        }
+        frame.with_caption(format!("{} seconds", self.seconds))
+    }
+
+    fn advance(&mut self) -> bool {
+        self.seconds += 1;
+        true
+    }
+}
+
+pub fn animation(input: &str, params: &Params) -> Result<Box<dyn Render>, ParseError> {
+    Ok(Box::new(Floor {
+        day: Day14::parse_with(input, params)?,
+        seconds: 0,
+    }))
+}
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Part1 = i64;
+    type Part2 = Option<i64>;
+    const PARAMS: &'static [Param] = &[WIDTH, HEIGHT, SECONDS];
+
+    fn parse(input: &str) -> Result<Day14, ParseError> {
+        Day14::parse_with_bounds(input, BOUNDS)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Day14, ParseError> {
+        let bounds = Rect {
+            x: 0,
+            y: 0,
+            width: params.get(&WIDTH),
+            height: params.get(&HEIGHT),
+        };
+        let mut ret = Day14::parse_with_bounds(input, bounds)?;
+        ret.seconds = params.get(&SECONDS);
+        Ok(ret)
+    }
+
+    fn part1(&mut self) -> i64 {
+        let positions = self
+            .robots
+            .iter()
+            .map(|r| r.get_position(self.seconds, &self.bounds))
+            .collect::<Vec<Point>>();
+        let solution = self
+            .bounds
+            .get_quadrants(CenterLine::Exclude)
+            .iter()
+            .map(|q| positions.iter().filter(|p| q.contains_point(p)).count())
+            .product::<usize>();
+        solution as i64
+    }
+
+    fn part2(&mut self) -> Option<i64> {
+        self.part2_within(&Token::unlimited())
+    }
+
+    /// `None` when no arrangement looks like a tree.
+    fn part2_within(&mut self, token: &Token) -> Option<i64> {
+        // half of the robots should have at least two neighbours... ...probably
+        // the positions repeat after width * height seconds at the latest
+        for i in 1..=self.bounds.width * self.bounds.height {
+            if token.expired() {
+                return None;
+            }
+            let positions = self
+                .robots
+                .iter()
+                .map(|r| r.get_position(i, &self.bounds))
+                .collect::<HashSet<Point>>();
+            let two_neighbours = positions
+                .iter()
+                .filter(|p| p.neighbours().filter(|n| positions.contains(n)).count() >= 2)
+                .count();
+
+            if two_neighbours > positions.len() / 2 {
+                return Some(i);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_parse() {
+        let input = r#"p=56,68 v=1,-12"#;
+        let robot = Robot::parse(0, input).expect("Invalid input");
+        assert!(robot.position == Point { x: 56, y: 68 });
+        assert!(robot.velocity == Point { x: 1, y: -12 });
+    }
+
+    #[test]
+    fn test_sample() {
+        let input = r#"p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3"#;
+        let bounds = Rect {
+            x: 0,
+            y: 0,
+            width: 11,
+            height: 7,
+        };
+        let mut day = Day14::parse_with_bounds(input, bounds).expect("Invalid input");
+        let part1 = day.part1();
+        assert!(part1 == 12, "Invalid solution");
+    }
+}
@@ -0,0 +1,137 @@
+// work in progress, most of this is not wired up yet
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::utils::{PointT, RectT};
+
+type Point = PointT<i64>;
+type Rect = RectT<i64>;
+type Level = Vec<Vec<Option<Rc<RefCell<dyn Block>>>>>;
+
+const CLEAR: char = '.';
+const WALL: char = '#';
+const CRATE: char = 'O';
+const ROBOT: char = '@';
+
+trait Block {
+    fn try_push(&self, dir: Point) -> bool;
+    fn push(&mut self, dir: Point);
+}
+struct Wall {}
+impl Block for Wall {
+    fn try_push(&self, _: Point) -> bool {
+        false
+    }
+
+    fn push(&mut self, _: Point) {
+        panic!("Do not ever call this");
+    }
+}
+struct Crate {
+    day: Rc<RefCell<Day15>>,
+    position: Point,
+    width: usize,
+}
+impl Block for Crate {
+    fn try_push(&self, _dir: Point) -> bool {
+        true
+    }
+    fn push(&mut self, _dir: Point) {
+        todo!()
+    }
+}
+
+pub struct Day15 {
+    bounds: Rect,
+    blocks: Vec<Rc<RefCell<dyn Block>>>,
+    level: Level,
+}
+
+impl Day15 {
+    fn parse_level(_input: &str, _width: usize) -> Rc<RefCell<Day15>> {
+        let ret = Rc::new(RefCell::new(Day15 {
+            bounds: Rect {
+                x: 0,
+                y: 0,
+                width: 0,
+                height: 0,
+            },
+            blocks: Vec::new(),
+            level: Vec::new(),
+        }));
+        //let mut blocks: Vec<Rc<dyn Block>> = Vec::new();
+        //let mut level: Vec<Vec<Option<Rc<dyn Block>>>> = Vec::new();
+        //let mut parsing_level = true;
+        //for (y, line) in input.lines().enumerate() {
+        //    if parsing_level {
+        //        if line.is_empty() {
+        //            parsing_level = false;
+        //
+        //            //ret.bounds.height = y as i64;
+        //        }
+        //        for (x, c) in line.chars().enumerate() {
+        //            match c {
+        //                CLEAR => {}
+        //                WALL => {
+        //                    let wall = Rc::new(RefCell::new(Wall {}));
+        //                    let mut tmp = ret.get_mut();
+        //                    tmp.blocks.push(wall.clone());
+        //                    //ret.borrow_mut().borrow().level[y][x] = Some(wall);
+        //                }
+        //                CRATE => {
+        //                    let crate_ = Rc::new(RefCell::new(Crate {
+        //                        day: ret,
+        //                        position: Point {
+        //                            x: x as i64,
+        //                            y: y as i64,
+        //                        },
+        //                        width,
+        //                    }));
+        //                    //ret.get_mut().blocks.push(crate_.clone());
+        //                    //ret.get_mut().level[y][x] = Some(crate_);
+        //                }
+        //                ROBOT => {}
+        //                c => panic!("Invalid character in map."),
+        //            }
+        //        }
+        //    } else {
+        //    }
+        //}
+        return ret;
+    }
+
+    fn simulate(&mut self) {}
+}
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day15, ParseError> {
+        let input = Input::new(input);
+        let day = Day15::parse_level(input.as_str(), 1);
+        Ok(Rc::try_unwrap(day)
+            .ok()
+            .expect("Level is still referenced")
+            .into_inner())
+    }
+
+    fn part1(&mut self) -> i64 {
+        todo!("finish day15")
+    }
+
+    fn part2(&mut self) -> i64 {
+        todo!("finish day15")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_parse() {}
+}
@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use crate::event;
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::render::{Cell, Frame};
+use crate::solution::Solution;
+use crate::utils::search::dijkstra;
+use crate::utils::{Direction, Grid, PointT};
+
+type Point = PointT<i64>;
+/// Position and facing of the reindeer.
+pub type State = (Point, Direction);
+
+const START: char = 'S';
+const END: char = 'E';
+const WALL: char = '#';
+
+pub struct Day16 {
+    level: Grid<char>,
+    start: Point,
+    end: Point,
+    direction: Direction,
+    solution: Option<i64>,
+    path: HashSet<Point>,
+}
+
+impl Day16 {
+    pub fn level(&self) -> &Grid<char> {
+        &self.level
+    }
+
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    pub fn end(&self) -> Point {
+        self.end
+    }
+
+    /// The moves from a state with their scores.
+    pub fn successors(&self, (pos, dir): &State) -> Vec<(State, i64)> {
+        let mut ret = vec![
+            ((*pos, dir.turn_right()), 1000),
+            ((*pos, dir.turn_left()), 1000),
+        ];
+        let target = pos.step(*dir);
+        match self.level.get(&target) {
+            Some(&WALL) | None => {}
+            Some(_) => ret.push(((target, *dir), 1)),
+        }
+        ret
+    }
+
+    fn solve(&mut self) {
+        let paths = dijkstra((self.start, self.direction), |state| self.successors(state));
+        let ends = Direction::CARDINAL
+            .map(|dir| (self.end, dir))
+            .into_iter()
+            .filter_map(|state| Some((paths.distance(&state)?, state)))
+            .collect::<Vec<_>>();
+        self.solution = ends.iter().map(|(score, _)| *score).min();
+        let best = ends
+            .iter()
+            .filter(|(score, _)| Some(*score) == self.solution)
+            .map(|(_, state)| state);
+        self.path = paths
+            .nodes_on_paths(best)
+            .into_iter()
+            .map(|(pos, _)| pos)
+            .collect();
+        event!(
+            Debug,
+            "best score {:?} on {} tiles\n{}",
+            self.solution,
+            self.path.len(),
+            self.frame()
+        );
+    }
+
+    /// The lowest score to reach the end, `None` if it can not be reached.
+    pub fn best_score(&mut self) -> Option<i64> {
+        if self.solution.is_none() {
+            self.solve();
+        }
+        self.solution
+    }
+
+    /// Every tile on any of the best paths.
+    pub fn best_tiles(&mut self) -> &HashSet<Point> {
+        if self.solution.is_none() {
+            self.solve();
+        }
+        &self.path
+    }
+
+    /// The level with the tiles of the best paths marked.
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new(&self.level, |c| Cell::plain(*c));
+        frame.overlay(self.path.iter().copied(), Cell::plain('O'));
+        frame
+    }
+}
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day16, ParseError> {
+        let input = Input::new(input);
+        let level = Grid::parse_chars(input.as_str())?;
+        return match (level.find(&START), level.find(&END)) {
+            (Some(start_pos), Some(end_pos)) => Ok(Day16 {
+                level,
+                start: start_pos,
+                direction: Direction::East,
+                end: end_pos,
+                solution: None,
+                path: HashSet::new(),
+            }),
+            (None, _) => Err(ParseError::at_end(input.as_str(), "a start tile 'S'")),
+            (_, None) => Err(ParseError::at_end(input.as_str(), "an end tile 'E'")),
+        };
+    }
+
+    fn part1(&mut self) -> i64 {
+        return self.best_score().expect("Maze is not solvable.");
+    }
+
+    fn part2(&mut self) -> i64 {
+        self.best_score().expect("Maze is not solvable.");
+        return self.best_tiles().len() as i64;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_example1() {
+        let input = r#"###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############"#;
+        let mut day = Day16::parse(input).expect("Invalid input");
+        assert!(day.part1() == 7036);
+        assert!(day.part2() == 45);
+    }
+    #[test]
+    fn test_example2() {
+        let input = r#"#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################"#;
+        let mut day = Day16::parse(input).expect("Invalid input");
+        assert!(day.part1() == 11048);
+        assert!(day.part2() == 64);
+    }
+}
@@ -0,0 +1,364 @@
+use itertools::Itertools;
+
+use crate::cancel::Token;
+use crate::input::{parse_key_value, split_key_value, Input};
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+/// Instructions executed between two looks at the cancellation token.
+const CHECK_INTERVAL: u32 = 4096;
+/// Opcodes whose operand is a combo operand, where 7 is reserved.
+const COMBO_OPCODES: [i32; 5] = [0, 2, 5, 6, 7];
+/// Opcode of `jnz`, its operand is the jump target.
+const JNZ: i32 = 3;
+
+/// The 3-bit computer, `out` collects the numbers the program printed.
+pub struct Machine {
+    pub reg_a: i64,
+    pub reg_b: i64,
+    pub reg_c: i64,
+    pub i_ptr: usize,
+    pub program: Vec<i32>,
+    pub out: Vec<i32>,
+}
+
+impl Machine {
+    /// Rejects programs that would fail at runtime: the reserved combo operand 7
+    /// and jumps into the middle of an instruction.
+    pub fn parse(input: &str) -> Result<Machine, ParseError> {
+        let mut a: Option<i64> = None;
+        let mut b: Option<i64> = None;
+        let mut c: Option<i64> = None;
+        let mut p: Vec<i32> = Vec::new();
+        let input = Input::new(input);
+        for section in input.sections() {
+            for (y, line) in section.numbered_lines() {
+                let (key, values) = split_key_value(y, line)?;
+                if key == "Program" {
+                    let raw = values.split(',').collect::<Vec<&str>>();
+                    for v in raw.iter() {
+                        match v.parse::<i32>() {
+                            Ok(value @ 0..=7) => p.push(value),
+                            _ => return Err(ParseError::in_line(y, line, v, "a 3-bit number")),
+                        }
+                    }
+                    if !p.len().is_multiple_of(2) {
+                        return Err(ParseError::new(y, line.len(), "an operand"));
+                    }
+                    for i in (0..p.len()).step_by(2) {
+                        let (opcode, operand) = (p[i], p[i + 1]);
+                        if COMBO_OPCODES.contains(&opcode) && operand == 7 {
+                            return Err(ParseError::in_line(
+                                y,
+                                line,
+                                raw[i + 1],
+                                "a combo operand",
+                            ));
+                        }
+                        if opcode == JNZ && operand % 2 == 1 {
+                            return Err(ParseError::in_line(
+                                y,
+                                line,
+                                raw[i + 1],
+                                "an even jump target",
+                            ));
+                        }
+                    }
+                    continue;
+                }
+                let (_, value) = parse_key_value(y, line, "a register value")?;
+                if value < 0 {
+                    return Err(ParseError::in_line(
+                        y,
+                        line,
+                        values,
+                        "a non-negative register value",
+                    ));
+                }
+                match key {
+                    "Register A" => a = Some(value),
+                    "Register B" => b = Some(value),
+                    "Register C" => c = Some(value),
+                    _ => return Err(ParseError::new(y, 0, "a register or the program")),
+                }
+            }
+        }
+        match (a, b, c) {
+            (Some(a), Some(b), Some(c)) => Ok(Machine {
+                reg_a: a,
+                reg_b: b,
+                reg_c: c,
+                i_ptr: 0,
+                program: p,
+                out: Vec::new(),
+            }),
+            _ => Err(ParseError::at_end(input.as_str(), "registers A, B and C")),
+        }
+    }
+
+    fn combo(&self, value: i32) -> i64 {
+        return match value {
+            0..=3 => value as i64,
+            4 => self.reg_a,
+            5 => self.reg_b,
+            6 => self.reg_c,
+            _ => panic!("Invalid combo"),
+        };
+    }
+
+    /// Register A divided by two to the power of the combo operand, registers
+    /// never get negative so powers beyond `i64` leave 0.
+    fn divide(&self, value: i32) -> i64 {
+        u32::try_from(self.combo(value))
+            .ok()
+            .and_then(|exp| 2_i64.checked_pow(exp))
+            .map_or(0, |divisor| self.reg_a / divisor)
+    }
+
+    fn op_adv(&mut self, value: i32) {
+        self.i_ptr += 2;
+        self.reg_a = self.divide(value);
+    }
+
+    fn op_bxl(&mut self, value: i32) {
+        self.i_ptr += 2;
+        let result = self.reg_b ^ value as i64;
+        self.reg_b = result;
+    }
+
+    fn op_bst(&mut self, value: i32) {
+        self.i_ptr += 2;
+        self.reg_b = self.combo(value).rem_euclid(8);
+    }
+    fn op_jnz(&mut self, value: i32) {
+        if self.reg_a == 0 {
+            self.i_ptr += 2;
+        } else {
+            self.i_ptr = value as usize
+        }
+    }
+
+    fn op_bxc(&mut self, _: i32) {
+        self.i_ptr += 2;
+        let result = self.reg_b ^ self.reg_c;
+        self.reg_b = result;
+    }
+
+    fn op_out(&mut self, value: i32) {
+        self.i_ptr += 2;
+        let result = self.combo(value).rem_euclid(8);
+        self.out.push(result as i32);
+    }
+
+    fn op_bdv(&mut self, value: i32) {
+        self.i_ptr += 2;
+        self.reg_b = self.divide(value);
+    }
+    fn op_cdv(&mut self, value: i32) {
+        self.i_ptr += 2;
+        self.reg_c = self.divide(value);
+    }
+    /// Runs until the instruction pointer leaves the program.
+    pub fn run(&mut self) {
+        self.run_within(&Token::unlimited());
+    }
+
+    /// Runs until the instruction pointer leaves the program or `token` expires,
+    /// `false` when it expired. A program jumping back forever never stops otherwise.
+    pub fn run_within(&mut self, token: &Token) -> bool {
+        let mut steps: u32 = 0;
+        while self.i_ptr < self.program.len() {
+            steps = steps.wrapping_add(1);
+            if steps.is_multiple_of(CHECK_INTERVAL) && token.expired() {
+                return false;
+            }
+            let value = self.program[self.i_ptr + 1];
+            match self.program[self.i_ptr] {
+                0 => self.op_adv(value),
+                1 => self.op_bxl(value),
+                2 => self.op_bst(value),
+                3 => self.op_jnz(value),
+                4 => self.op_bxc(value),
+                5 => self.op_out(value),
+                6 => self.op_bdv(value),
+                7 => self.op_cdv(value),
+                _ => panic!("Invalid opcode"),
+            }
+        }
+        true
+    }
+}
+impl Machine {
+    /// Runs the program from the start with register A set to `a` and the other
+    /// registers cleared, returns the output.
+    pub fn run_with(&mut self, a: i64) -> &[i32] {
+        self.reset(a);
+        self.run();
+        &self.out
+    }
+
+    fn reset(&mut self, a: i64) {
+        self.out.clear();
+        self.reg_a = a;
+        self.reg_b = 0;
+        self.reg_c = 0;
+        self.i_ptr = 0;
+    }
+}
+
+pub struct Day17 {
+    machine: Machine,
+}
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Part1 = Option<String>;
+    type Part2 = Option<i64>;
+
+    fn parse(input: &str) -> Result<Day17, ParseError> {
+        Ok(Day17 {
+            machine: Machine::parse(input)?,
+        })
+    }
+
+    fn part1(&mut self) -> Option<String> {
+        self.part1_within(&Token::unlimited())
+    }
+
+    fn part2(&mut self) -> Option<i64> {
+        self.part2_within(&Token::unlimited())
+    }
+
+    fn part1_within(&mut self, token: &Token) -> Option<String> {
+        let m = &mut self.machine;
+        m.run_within(token).then(|| m.out.iter().join(","))
+    }
+
+    /// `None` when no register A makes the program output itself.
+    fn part2_within(&mut self, token: &Token) -> Option<i64> {
+        self.find_quine(0, 0, token)
+    }
+}
+
+impl Day17 {
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    /// Smallest register A for which the program outputs itself. Every round of the
+    /// program outputs one number and shifts A by 3 bits, so A is built 3 bits at a
+    /// time from the last output backwards. `prefix` outputs the last `matched`
+    /// numbers, candidates that lead nowhere are backtracked.
+    fn find_quine(&mut self, prefix: i64, matched: usize, token: &Token) -> Option<i64> {
+        let len = self.machine.program.len();
+        if matched == len {
+            return Some(prefix);
+        }
+        for a in (0..8).map(|bits| prefix << 3 | bits).filter(|a| *a != 0) {
+            let m = &mut self.machine;
+            m.reset(a);
+            if !m.run_within(token) {
+                return None;
+            }
+            if m.out == m.program[len - matched - 1..] {
+                if let Some(quine) = self.find_quine(a, matched + 1, token) {
+                    return Some(quine);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::params::Params;
+    use crate::solution::{solve, SolveError};
+    #[test]
+    fn test_example1() {
+        let input = r#"Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0"#;
+        match Machine::parse(input) {
+            Ok(mut m) => {
+                m.run();
+                assert!(m.out == vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+            }
+            Err(e) => panic!("Invalid input: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_example2() {
+        let input = r#"Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0"#;
+        match Machine::parse(input) {
+            Ok(mut m) => {
+                let mut solution: i64 = 0;
+                for i in 0_i64.. {
+                    m.out.clear();
+                    m.reg_a = i;
+                    m.reg_b = 0;
+                    m.reg_c = 0;
+                    m.i_ptr = 0;
+                    m.run();
+                    if m.out == m.program {
+                        solution = i;
+                        break;
+                    }
+                }
+                assert!(solution == 117440);
+            }
+            Err(e) => panic!("Invalid input: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_quine() {
+        let input = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0";
+        let mut day = Day17::parse(input).expect("Invalid input");
+        assert!(day.part2() == Some(117440));
+
+        // the first candidate for the last eight numbers is a dead end
+        let input = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,2,7,5,4,2,1,3,5,5,0,3,3,0";
+        let mut day = Day17::parse(input).expect("Invalid input");
+        assert!(day.part2() == Some(38886108872180));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let registers = "Register A: 7\nRegister B: 0\nRegister C: 0\n\n";
+        let err = Machine::parse(&format!("{}Program: 0,1,5,7", registers)).err();
+        assert!(err == Some(ParseError::new(4, 15, "a combo operand")));
+        let err = Machine::parse(&format!("{}Program: 0,1,3,3", registers)).err();
+        assert!(err == Some(ParseError::new(4, 15, "an even jump target")));
+        let err =
+            Machine::parse("Register A: -1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1").err();
+        assert!(err == Some(ParseError::new(0, 12, "a non-negative register value")));
+        // a literal 7 is fine
+        assert!(Machine::parse(&format!("{}Program: 1,7,3,0", registers)).is_ok());
+    }
+
+    #[test]
+    fn test_large_shift() {
+        // A is divided by 2^100, which does not fit an i64
+        let input = "Register A: 100\nRegister B: 0\nRegister C: 0\n\nProgram: 0,4,5,4";
+        let mut day = Day17::parse(input).expect("Invalid input");
+        assert!(day.part1() == Some("0".to_string()));
+    }
+
+    #[test]
+    fn test_gave_up() {
+        // jumps back to the start forever
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0";
+        let token = Token::with_budget(std::time::Duration::from_millis(10));
+        let err = solve::<Day17>(input, &Params::new(), &token).err();
+        assert!(matches!(err, Some(SolveError::GaveUp { part: 1, .. })));
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::HashSet;
+
+use crate::cancel::Token;
+use crate::event;
+use crate::input::Input;
+use crate::params::{Param, Params};
+use crate::parse::{parse_in_line, ParseError};
+use crate::render::{Cell, Colour, Frame, Render};
+use crate::solution::Solution;
+use crate::utils::search::astar;
+use crate::utils::{Grid, PointT, RectT};
+
+type Point = PointT<i64>;
+type Rect = RectT<i64>;
+
+/// The memory space of the real input, the example is smaller.
+pub const BOUNDS: Rect = Rect {
+    x: 0,
+    y: 0,
+    width: 71,
+    height: 71,
+};
+/// Bytes fallen before part 1 looks for the way out.
+pub const DROPS: usize = 1024;
+
+const WIDTH: Param = Param {
+    name: "width",
+    default: BOUNDS.width,
+    min: 1,
+    max: 1000,
+    help: "Width of the memory space, 7 in the example",
+};
+const HEIGHT: Param = Param {
+    name: "height",
+    default: BOUNDS.height,
+    min: 1,
+    max: 1000,
+    help: "Height of the memory space, 7 in the example",
+};
+const FALLEN: Param = Param {
+    name: "drops",
+    default: DROPS as i64,
+    min: 0,
+    max: 1_000_000,
+    help: "Bytes fallen before part 1 looks for the way out, 12 in the example",
+};
+
+const CLEAR: char = '.';
+const CORRUPTED: char = '#';
+
+pub struct Day18 {
+    bounds: Rect,
+    level: Grid<char>,
+    falling: Vec<Point>,
+    falling_idx: usize,
+    drops: usize,
+}
+
+impl Day18 {
+    /// Parses the bytes falling into a memory space of any size, `parse` uses `BOUNDS`.
+    pub fn parse_with_bounds(input: &str, bounds: Rect) -> Result<Day18, ParseError> {
+        let input = Input::new(input);
+        let level = Grid::new(bounds.width as usize, bounds.height as usize, CLEAR);
+        let mut falling = Vec::new();
+        for (y, line) in input.lines().enumerate() {
+            let Some((px, py)) = line.split_once(',') else {
+                return Err(ParseError::new(y, 0, "a coordinate like 5,4"));
+            };
+            let p = Point {
+                x: parse_in_line(y, line, px, "an x coordinate")?,
+                y: parse_in_line(y, line, py, "a y coordinate")?,
+            };
+            if !bounds.contains_point(&p) {
+                return Err(ParseError::new(
+                    y,
+                    0,
+                    "a coordinate inside the memory space",
+                ));
+            }
+            falling.push(p);
+        }
+        Ok(Day18 {
+            bounds,
+            level,
+            falling,
+            falling_idx: 0,
+            drops: DROPS,
+        })
+    }
+
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// The memory space with the bytes fallen so far.
+    pub fn level(&self) -> &Grid<char> {
+        &self.level
+    }
+
+    /// Corrupts the tile of the next byte, panics when all bytes have fallen.
+    pub fn drop_byte(&mut self) {
+        let p = &self.falling[self.falling_idx];
+        event!(Trace, "byte {} falls at {}", self.falling_idx, p);
+        self.falling_idx += 1;
+        self.level[p] = CORRUPTED;
+    }
+
+    fn solve(&self) -> Option<i64> {
+        self.shortest_path().map(|(cost, _)| cost)
+    }
+
+    /// Steps and tiles of a shortest path from the top left to the bottom right corner.
+    pub fn shortest_path(&self) -> Option<(i64, Vec<Point>)> {
+        let start = Point { x: 0, y: 0 };
+        let goal = Point {
+            x: self.bounds.width - 1,
+            y: self.bounds.height - 1,
+        };
+        if self.level[&start] == CORRUPTED {
+            return None;
+        }
+        let successors = |p: &Point| {
+            self.level
+                .neighbours(p)
+                .filter(|n| self.level[n] != CORRUPTED)
+                .map(|n| (n, 1))
+                .collect::<Vec<_>>()
+        };
+        astar(start, successors, |p| p.distance(&goal), |p| *p == goal)
+    }
+
+    pub fn get_last_dropped(&self) -> Option<&Point> {
+        self.falling.get(self.falling_idx.checked_sub(1)?)
+    }
+}
+
+impl Render for Day18 {
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new(&self.level, |c| match *c {
+            CORRUPTED => Cell::new(CORRUPTED, Colour::Grey),
+            c => Cell::plain(c),
+        });
+        let path = self.shortest_path();
+        if let Some((_, path)) = &path {
+            frame.overlay(path.iter().copied(), Cell::new('O', Colour::Green));
+        }
+        if let Some(p) = self.get_last_dropped() {
+            frame.set(p, Cell::new(CORRUPTED, Colour::Red));
+        }
+        let caption = match path {
+            Some((cost, _)) => format!("{} bytes fallen, {} steps", self.falling_idx, cost),
+            None => format!("{} bytes fallen, blocked", self.falling_idx),
+        };
+        frame.with_caption(caption)
+    }
+
+    fn advance(&mut self) -> bool {
+        if self.falling_idx >= self.falling.len() || self.solve().is_none() {
+            return false;
+        }
+        self.drop_byte();
+        true
+    }
+}
+
+/// Bytes falling until the exit is blocked.
+pub fn animation(input: &str, params: &Params) -> Result<Box<dyn Render>, ParseError> {
+    Ok(Box::new(Day18::parse_with(input, params)?))
+}
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Part1 = Option<i64>;
+    type Part2 = Option<String>;
+    const PARAMS: &'static [Param] = &[WIDTH, HEIGHT, FALLEN];
+
+    fn parse(input: &str) -> Result<Day18, ParseError> {
+        Day18::parse_with_bounds(input, BOUNDS)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Day18, ParseError> {
+        let bounds = Rect {
+            x: 0,
+            y: 0,
+            width: params.get(&WIDTH),
+            height: params.get(&HEIGHT),
+        };
+        let mut ret = Day18::parse_with_bounds(input, bounds)?;
+        ret.drops = params.get(&FALLEN) as usize;
+        Ok(ret)
+    }
+
+    /// `None` when the way out is already blocked.
+    fn part1(&mut self) -> Option<i64> {
+        while self.falling_idx < self.drops.min(self.falling.len()) {
+            self.drop_byte();
+        }
+        self.solve()
+    }
+
+    fn part2(&mut self) -> Option<String> {
+        self.part2_within(&Token::unlimited())
+    }
+
+    /// `None` when the way out stays open after all bytes have fallen.
+    fn part2_within(&mut self, token: &Token) -> Option<String> {
+        let mut path: HashSet<Point> = HashSet::new();
+        while self.falling_idx < self.falling.len() {
+            if token.expired() {
+                return None;
+            }
+            self.drop_byte();
+            let coord = self.get_last_dropped().expect("A byte has just fallen");
+            // only a byte landing on the current path can block the way out
+            if !path.is_empty() && !path.contains(coord) {
+                continue;
+            }
+            match self.shortest_path() {
+                Some((steps, p)) => {
+                    event!(
+                        Debug,
+                        "byte at {} is in the way, new path steps={}",
+                        coord,
+                        steps
+                    );
+                    path = p.into_iter().collect();
+                }
+                None => return Some(format!("{},{}", coord.x, coord.y)),
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::params::Overrides;
+    #[test]
+    fn test_example1() {
+        let input = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0";
+        let bounds = Rect {
+            x: 0,
+            y: 0,
+            width: 7,
+            height: 7,
+        };
+        let mut solver = Day18::parse_with_bounds(input, bounds).expect("Invalid input");
+        for _ in 0..12 {
+            solver.drop_byte();
+        }
+        let result = solver.solve();
+        assert!(result == Some(22));
+        loop {
+            solver.drop_byte();
+            if solver.solve().is_none() {
+                break;
+            }
+        }
+        assert!(solver.get_last_dropped() == Some(&Point { x: 6, y: 1 }))
+    }
+
+    #[test]
+    fn test_never_blocked() {
+        let params = Overrides::parse("18 width 3\n18 height 3\n18 drops 5").expect("Invalid");
+        let mut day = Day18::parse_with("1,1\n2,1", &params.day(18)).expect("Invalid input");
+        assert!(day.part1() == Some(4));
+        assert!(day.part2().is_none());
+
+        // blocked before the first step
+        let params = Overrides::parse("18 width 2\n18 height 2\n18 drops 2").expect("Invalid");
+        let mut day = Day18::parse_with("1,0\n0,1", &params.day(18)).expect("Invalid input");
+        assert!(day.part1().is_none());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let bounds = Rect {
+            x: 0,
+            y: 0,
+            width: 7,
+            height: 7,
+        };
+        let err = Day18::parse_with_bounds("5,4\n4,x", bounds).err();
+        assert!(err == Some(ParseError::new(1, 2, "a y coordinate")));
+        let err = Day18::parse_with_bounds("5,4\n4,7", bounds).err();
+        assert!(
+            err == Some(ParseError::new(
+                1,
+                0,
+                "a coordinate inside the memory space"
+            ))
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use crate::event;
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct Day19 {
+    towels: Vec<String>,
+    designs: Vec<String>,
+    solutions: Vec<i64>,
+}
+
+impl Day19 {
+    fn parse_towels(input: &str) -> Day19 {
+        let mut ret = Day19 {
+            towels: Vec::new(),
+            designs: Vec::new(),
+            solutions: Vec::new(),
+        };
+        let input = Input::new(input);
+        let mut sections = input.sections().into_iter();
+        if let Some(towels) = sections.next() {
+            for line in towels.text.lines() {
+                ret.towels
+                    .extend(line.split(',').map(|s| s.trim().to_string()));
+            }
+        }
+        ret.designs
+            .extend(sections.flat_map(|s| s.text.lines()).map(str::to_string));
+        return ret;
+    }
+
+    fn rec(&self, memo: &mut HashMap<String, i64>, design: &str) -> i64 {
+        let mut ret: i64 = 0;
+        for towel in self.towels.iter() {
+            if design.starts_with(towel) {
+                if design.len() == towel.len() {
+                    ret += 1;
+                    continue;
+                }
+                let slice = &design[towel.len()..];
+                ret += match memo.entry(slice.to_string()) {
+                    Entry::Occupied(o) => {
+                        event!(Trace, "memo hit design={} count={}", slice, o.get());
+                        *o.get()
+                    }
+                    Entry::Vacant(_) => self.rec(memo, slice),
+                };
+            }
+        }
+        memo.insert(design.to_string(), ret);
+        return ret;
+    }
+
+    pub fn towels(&self) -> &[String] {
+        &self.towels
+    }
+
+    pub fn designs(&self) -> &[String] {
+        &self.designs
+    }
+
+    /// Number of ways to arrange the towels into `design`.
+    pub fn count_arrangements(&self, design: &str) -> i64 {
+        let mut memo: HashMap<String, i64> = HashMap::new();
+        self.rec(&mut memo, design)
+    }
+
+    fn solve(&mut self) {
+        if !self.solutions.is_empty() {
+            return;
+        }
+        for design in self.designs.iter() {
+            self.solutions.push(self.count_arrangements(design));
+        }
+    }
+}
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Day19, ParseError> {
+        let ret = Day19::parse_towels(input);
+        if ret.towels.iter().any(|t| t.is_empty()) {
+            return Err(ParseError::new(0, 0, "a comma separated list of towels"));
+        }
+        Ok(ret)
+    }
+
+    fn part1(&mut self) -> i64 {
+        self.solve();
+        self.solutions
+            .iter()
+            .fold(0_i64, |acc, v| if *v > 0 { acc + 1 } else { acc })
+    }
+
+    fn part2(&mut self) -> i64 {
+        self.solve();
+        self.solutions.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_example1() {
+        let input = r#"r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb"#;
+
+        let mut solver = Day19::parse(input).expect("Invalid input");
+        assert!(solver.part1() == 6);
+        assert!(solver.part2() == 16);
+    }
+}
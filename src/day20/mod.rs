@@ -0,0 +1,186 @@
+use std::cmp::{max, min};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::input::Input;
+use crate::params::{Param, Params};
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::utils::search::bfs;
+use crate::utils::{Grid, PointT};
+
+type Point = PointT<i64>;
+
+const WALL: char = '#';
+const START: char = 'S';
+const END: char = 'E';
+
+const THRESHOLD: Param = Param {
+    name: "threshold",
+    default: 100,
+    min: 1,
+    max: 1_000_000,
+    help: "Picoseconds a cheat has to save to be counted",
+};
+
+pub struct Day20 {
+    level: Grid<char>,
+    start: Point,
+    end: Point,
+    threshold: i64,
+}
+
+impl Day20 {
+    pub fn level(&self) -> &Grid<char> {
+        &self.level
+    }
+
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    pub fn end(&self) -> Point {
+        self.end
+    }
+
+    fn get_distances(&self, start: &Point) -> HashMap<Point, i64> {
+        let successors = |p: &Point| {
+            self.level
+                .neighbours(p)
+                .filter(|n| self.level[n] != WALL)
+                .collect::<Vec<_>>()
+        };
+        return bfs(*start, successors).into_distances();
+    }
+
+    /// Number of distinct cheats by the picoseconds they save, for cheats
+    /// lasting any number of picoseconds in `range`.
+    pub fn calculate_cheats(&self, range: Range<i64>) -> HashMap<i64, i64> {
+        let start_distances = self.get_distances(&self.start);
+        let end_distances = self.get_distances(&self.end);
+        let race_distance = start_distances
+            .get(&self.end)
+            .expect("Maze is not solvable.");
+        let mut shortcuts: HashMap<(Point, Point), i64> = HashMap::new();
+        for (cheat_start, dist) in start_distances.iter() {
+            for cheat_distance in range.clone() {
+                for cheat_end in cheat_start.manhattan_ring(cheat_distance) {
+                    match end_distances.get(&cheat_end) {
+                        Some(end_dist) => {
+                            let shortcut_dist = dist + end_dist + cheat_distance;
+                            if shortcut_dist < *race_distance {
+                                let key =
+                                    (min(*cheat_start, cheat_end), max(*cheat_start, cheat_end));
+                                match shortcuts.entry(key) {
+                                    Entry::Occupied(o) => assert!(*o.get() == shortcut_dist),
+                                    Entry::Vacant(v) => {
+                                        v.insert(*race_distance - shortcut_dist);
+                                    }
+                                }
+                            }
+                        }
+                        None => continue,
+                    }
+                }
+            }
+        }
+        let mut ret = HashMap::new();
+        for shortcut_dist in shortcuts.into_values() {
+            *ret.entry(shortcut_dist).or_insert(0) += 1;
+        }
+        return ret;
+    }
+}
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    type Part1 = i64;
+    type Part2 = i64;
+    const PARAMS: &'static [Param] = &[THRESHOLD];
+
+    fn parse(input: &str) -> Result<Day20, ParseError> {
+        let input = Input::new(input);
+        let level = Grid::parse_chars(input.as_str())?;
+        match (level.find(&START), level.find(&END)) {
+            (Some(start), Some(end)) => Ok(Day20 {
+                level,
+                start,
+                end,
+                threshold: THRESHOLD.default,
+            }),
+            (None, _) => Err(ParseError::at_end(input.as_str(), "a start tile 'S'")),
+            (_, None) => Err(ParseError::at_end(input.as_str(), "an end tile 'E'")),
+        }
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Day20, ParseError> {
+        let mut ret = Day20::parse(input)?;
+        ret.threshold = params.get(&THRESHOLD);
+        Ok(ret)
+    }
+
+    fn part1(&mut self) -> i64 {
+        let cheats = self.calculate_cheats(2..3);
+        cheats.iter().fold(
+            0_i64,
+            |acc, (k, v)| {
+                if *k >= self.threshold {
+                    acc + v
+                } else {
+                    acc
+                }
+            },
+        )
+    }
+
+    fn part2(&mut self) -> i64 {
+        let cheats = self.calculate_cheats(2..21);
+        cheats.iter().fold(
+            0_i64,
+            |acc, (k, v)| {
+                if *k >= self.threshold {
+                    acc + v
+                } else {
+                    acc
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_example1() {
+        let input = r#"###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############"#;
+        let solver = Day20::parse(input).expect("Invalid input.");
+        let mut shortcuts = solver.calculate_cheats(2..3);
+        assert!(shortcuts.remove(&2) == Some(14));
+        assert!(shortcuts.remove(&4) == Some(14));
+        assert!(shortcuts.remove(&6) == Some(2));
+        assert!(shortcuts.remove(&8) == Some(4));
+        assert!(shortcuts.remove(&10) == Some(2));
+        assert!(shortcuts.remove(&12) == Some(3));
+        assert!(shortcuts.remove(&20) == Some(1));
+        assert!(shortcuts.remove(&36) == Some(1));
+        assert!(shortcuts.remove(&38) == Some(1));
+        assert!(shortcuts.remove(&40) == Some(1));
+        assert!(shortcuts.remove(&64) == Some(1));
+    }
+}
@@ -0,0 +1,247 @@
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use std::collections::{HashMap, HashSet};
+
+use crate::event;
+use crate::input::Input;
+use crate::params::{Param, Params};
+use crate::parse::{integers, parse_n_integers, ParseError};
+use crate::solution::Solution;
+use crate::utils::{Direction, PointT};
+
+type Point = PointT<i32>;
+type Memo = HashMap<(i32, Vec<char>), i64>;
+
+const UP: char = '^';
+const DOWN: char = 'v';
+const LEFT: char = '<';
+const RIGHT: char = '>';
+const ACTION: char = 'A';
+
+const NUMPAD_START: Point = Point { x: 2, y: 3 };
+const DIRPAD_START: Point = Point { x: 2, y: 0 };
+
+lazy_static! {
+    static ref NUMPAD: HashMap<char, Point> = {
+        let mut ret = HashMap::new();
+        ret.insert('7', Point { x: 0, y: 0 });
+        ret.insert('8', Point { x: 1, y: 0 });
+        ret.insert('9', Point { x: 2, y: 0 });
+        ret.insert('4', Point { x: 0, y: 1 });
+        ret.insert('5', Point { x: 1, y: 1 });
+        ret.insert('6', Point { x: 2, y: 1 });
+        ret.insert('1', Point { x: 0, y: 2 });
+        ret.insert('2', Point { x: 1, y: 2 });
+        ret.insert('3', Point { x: 2, y: 2 });
+        ret.insert('0', Point { x: 1, y: 3 });
+        ret.insert(ACTION, NUMPAD_START);
+        ret
+    };
+    static ref DIRPAD: HashMap<char, Point> = {
+        let mut ret = HashMap::new();
+        ret.insert(UP, Point { x: 1, y: 0 });
+        ret.insert(ACTION, DIRPAD_START);
+        ret.insert(LEFT, Point { x: 0, y: 1 });
+        ret.insert(DOWN, Point { x: 1, y: 1 });
+        ret.insert(RIGHT, Point { x: 2, y: 1 });
+        ret
+    };
+    static ref NUMPAD_VALID: HashSet<Point> = HashSet::from_iter(NUMPAD.values().cloned());
+    static ref DIRPAD_VALID: HashSet<Point> = HashSet::from_iter(DIRPAD.values().cloned());
+}
+
+fn is_path_valid(start: &Point, path: &[char], valid: &HashSet<Point>) -> bool {
+    let mut p = *start;
+    for c in path.iter() {
+        match Direction::from_glyph(*c) {
+            Some(d) => {
+                p = p + d;
+                if !valid.contains(&p) {
+                    return false;
+                }
+            }
+            None => panic!("Invalid char in path"),
+        }
+    }
+    true
+}
+
+fn delta_to_path(delta: Point) -> Vec<char> {
+    let mut ret = Vec::new();
+    for _ in 0..delta.x.abs() {
+        ret.push(if delta.x > 0 { RIGHT } else { LEFT })
+    }
+    for _ in 0..delta.y.abs() {
+        ret.push(if delta.y > 0 { DOWN } else { UP })
+    }
+    return ret;
+}
+
+fn rec_dir(memo: &mut Memo, depth: i32, path: Vec<char>) -> i64 {
+    if let Some(ret) = memo.get(&(depth, path.clone())) {
+        event!(
+            Trace,
+            "memo hit depth={} path={}",
+            depth,
+            path.iter().collect::<String>()
+        );
+        return *ret;
+    }
+    let mut ret: i64 = 0;
+    if depth <= 0 {
+        ret = path.len() as i64;
+    } else {
+        let mut cursor = DIRPAD_START;
+        let mut last_char: Option<char> = None;
+        for c in path.iter() {
+            if last_char == Some(*c) {
+                // press A again
+                ret += rec_dir(memo, depth - 1, vec![ACTION]);
+                continue;
+            }
+            let target = DIRPAD.get(c).expect("Invalid dirpad character.");
+            let path = delta_to_path(*target - cursor);
+            let len = path.len();
+            ret += path
+                .into_iter()
+                .permutations(len)
+                .filter_map(|mut i| {
+                    if is_path_valid(&cursor, &i, &DIRPAD_VALID) {
+                        i.push(ACTION);
+                        Some(rec_dir(memo, depth - 1, i))
+                    } else {
+                        None
+                    }
+                })
+                .min()
+                .expect("Invalid input? Not a single valid dir path.");
+            cursor = *target;
+            last_char = Some(*c);
+        }
+    }
+    memo.insert((depth, path.clone()), ret);
+    ret
+}
+
+/// Length of the shortest button sequence typing `code` through `depth` robots
+/// on directional keypads.
+pub fn solve_line(code: &str, depth: i32) -> i64 {
+    let mut ret: i64 = 0;
+    let mut cursor = NUMPAD_START;
+    let mut memo: Memo = HashMap::new();
+    for c in code.chars() {
+        let target = NUMPAD.get(&c).expect("Invalid numpad character.");
+        let path = delta_to_path(*target - cursor);
+        let len = path.len();
+        ret += path
+            .into_iter()
+            .permutations(len)
+            .filter_map(|mut i| {
+                if is_path_valid(&cursor, &i, &NUMPAD_VALID) {
+                    i.push(ACTION);
+                    Some(rec_dir(&mut memo, depth, i))
+                } else {
+                    None
+                }
+            })
+            .min()
+            .expect("Invalid input? Not a single valid path.");
+        cursor = *target;
+    }
+    ret
+}
+
+/// Sum of the complexities of all codes in `input`, one per line.
+pub fn solve(input: &str, depth: i32) -> i64 {
+    let lines: Vec<&str> = input.lines().collect();
+    let nums: Vec<i64> = lines
+        .iter()
+        .map(|n| integers(n).next().expect("Codes are checked by parse"))
+        .collect();
+    let lengths: Vec<i64> = lines.iter().map(|n| solve_line(n, depth)).collect();
+    for (code, len) in lines.iter().zip(lengths.iter()) {
+        event!(Debug, "code={} depth={} presses={}", code, depth, len);
+    }
+    nums.iter()
+        .zip(lengths.iter())
+        .fold(0_i64, |acc, (num, len)| acc + *num * *len)
+}
+
+const PART1_ROBOTS: Param = Param {
+    name: "part1_robots",
+    default: 2,
+    min: 0,
+    max: 30,
+    help: "Robots on directional keypads in part 1",
+};
+const PART2_ROBOTS: Param = Param {
+    name: "part2_robots",
+    default: 25,
+    min: 0,
+    max: 30,
+    help: "Robots on directional keypads in part 2",
+};
+
+pub struct Day21 {
+    input: String,
+    robots: [i32; 2],
+}
+
+impl Day21 {
+    pub fn codes(&self) -> impl Iterator<Item = &str> {
+        self.input.lines()
+    }
+}
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    type Part1 = i64;
+    type Part2 = i64;
+    const PARAMS: &'static [Param] = &[PART1_ROBOTS, PART2_ROBOTS];
+
+    fn parse(input: &str) -> Result<Day21, ParseError> {
+        let input = Input::new(input);
+        for (y, line) in input.lines().enumerate() {
+            if let Some(x) = line.chars().position(|c| !NUMPAD.contains_key(&c)) {
+                return Err(ParseError::new(y, x, "a numeric keypad button"));
+            }
+            parse_n_integers::<i64, 1>(y, line, line, "a code with one number")?;
+        }
+        Ok(Day21 {
+            input: input.as_str().to_string(),
+            robots: [PART1_ROBOTS.default as i32, PART2_ROBOTS.default as i32],
+        })
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Day21, ParseError> {
+        let mut ret = Day21::parse(input)?;
+        ret.robots = [PART1_ROBOTS, PART2_ROBOTS].map(|p| params.get(&p) as i32);
+        Ok(ret)
+    }
+
+    fn part1(&mut self) -> i64 {
+        solve(&self.input, self.robots[0])
+    }
+
+    fn part2(&mut self) -> i64 {
+        solve(&self.input, self.robots[1])
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    #[test]
+    fn test_example1() {
+        let input = r#"029A
+980A
+179A
+456A
+379A"#;
+        let solution = solve(input, 2);
+        assert!(solution == 126384);
+        let sol2 = solve(input, 25);
+        assert!(sol2 == 154115708116294);
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+use crate::input::Input;
+use crate::params::{Param, Params};
+use crate::parse::{parse_in_line, ParseError};
+use crate::solution::Solution;
+use crate::utils::RingBuffer;
+
+const PRUNE: i64 = 16_777_216;
+
+/// The last four price changes, packed one byte each into the pattern key.
+type Window = RingBuffer<i8, 4>;
+const KEY_BITS: u32 = 8;
+
+const ROUNDS: Param = Param {
+    name: "rounds",
+    default: 2000,
+    min: 0,
+    max: 100_000,
+    help: "Secret numbers each buyer generates",
+};
+
+/// The secret number following `secret`.
+pub fn next_secret(secret: i64) -> i64 {
+    let mut num = secret;
+    num ^= num * 64;
+    num = num.rem_euclid(PRUNE);
+    num ^= num / 32;
+    num = num.rem_euclid(PRUNE);
+    num ^= num * 2048;
+    num.rem_euclid(PRUNE)
+}
+
+struct CalcResult {
+    number: i64,
+    patterns: HashMap<u64, i32>,
+}
+
+fn calculate(secret: i64, rounds: i64) -> CalcResult {
+    let mut patterns: HashMap<u64, i32> = HashMap::new();
+    let mut seq_buf = Window::new();
+    let mut num = secret;
+    let mut last_price = num.rem_euclid(10) as i8;
+    for _ in 0..rounds {
+        num = next_secret(num);
+        let price = num.rem_euclid(10) as i8;
+        seq_buf.push(price - last_price);
+
+        last_price = price;
+        if seq_buf.is_full() {
+            match patterns.entry(seq_buf.packed_key(KEY_BITS)) {
+                Entry::Occupied(_) => { /* ignore */ }
+                Entry::Vacant(v) => {
+                    v.insert(price as i32);
+                }
+            }
+        }
+    }
+    CalcResult {
+        number: num,
+        patterns,
+    }
+}
+
+pub struct Day22 {
+    secrets: Vec<i64>,
+    results: Vec<CalcResult>,
+    rounds: i64,
+}
+
+impl Day22 {
+    pub fn secrets(&self) -> &[i64] {
+        &self.secrets
+    }
+
+    fn calculate_all(&mut self) {
+        if self.results.is_empty() {
+            self.results = self
+                .secrets
+                .iter()
+                .map(|s| calculate(*s, self.rounds))
+                .collect();
+        }
+    }
+}
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    type Part1 = i64;
+    type Part2 = i64;
+    const PARAMS: &'static [Param] = &[ROUNDS];
+
+    fn parse(input: &str) -> Result<Day22, ParseError> {
+        let input = Input::new(input);
+        Ok(Day22 {
+            secrets: input
+                .lines()
+                .enumerate()
+                .map(|(y, n)| parse_in_line(y, n, n, "a secret number"))
+                .collect::<Result<Vec<i64>, ParseError>>()?,
+            results: Vec::new(),
+            rounds: ROUNDS.default,
+        })
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Day22, ParseError> {
+        let mut ret = Day22::parse(input)?;
+        ret.rounds = params.get(&ROUNDS);
+        Ok(ret)
+    }
+
+    fn part1(&mut self) -> i64 {
+        self.calculate_all();
+        self.results.iter().fold(0_i64, |acc, r| acc + r.number)
+    }
+
+    fn part2(&mut self) -> i64 {
+        self.calculate_all();
+        let mut all_patterns: HashSet<u64> = HashSet::new();
+        for r in self.results.iter() {
+            all_patterns.extend(r.patterns.keys());
+        }
+
+        let mut best_sum = 0;
+        for pattern in all_patterns {
+            let pat_sum = self.results.iter().fold(0_i64, |acc, r| {
+                acc + (*r.patterns.get(&pattern).unwrap_or(&0) as i64)
+            });
+            if pat_sum > best_sum {
+                best_sum = pat_sum;
+            }
+        }
+        best_sum
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_example1() {
+        let expected = vec![
+            15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+            5908254,
+        ];
+        let mut secret = 123;
+        for (i, exp) in expected.into_iter().enumerate() {
+            let result = calculate(123, i as i64 + 1);
+            assert!(result.number == exp);
+            secret = next_secret(secret);
+            assert!(secret == exp);
+        }
+    }
+
+    #[test]
+    fn test_example2() {
+        let input = [1, 2, 3, 2024];
+        let results: Vec<CalcResult> = input.iter().map(|i| calculate(*i, 2000)).collect();
+        let exp = Window::pack(&[-2, 1, -1, 3], KEY_BITS);
+        let mut sum = 0;
+        for r in results.iter() {
+            let res = r.patterns.get(&exp).unwrap_or(&0);
+            sum += res;
+        }
+        let res = results
+            .iter()
+            .fold(0, |acc, r| acc + r.patterns.get(&exp).unwrap_or(&0));
+        assert!(res == 23);
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct Day23 {
+    lan: HashMap<String, HashSet<String>>,
+}
+
+impl Day23 {
+    /// The computers each computer is directly connected to.
+    pub fn lan(&self) -> &HashMap<String, HashSet<String>> {
+        &self.lan
+    }
+}
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    type Part1 = i64;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Day23, ParseError> {
+        let input = Input::new(input);
+        let mut lan: HashMap<String, HashSet<String>> = HashMap::new();
+        for (y, line) in input.lines().enumerate() {
+            if let [a, b] = line.split('-').collect::<Vec<&str>>()[..] {
+                match lan.entry(a.to_string()) {
+                    Entry::Occupied(mut o) => {
+                        o.get_mut().insert(b.to_string());
+                    }
+                    Entry::Vacant(v) => {
+                        v.insert(HashSet::from([b.to_string()]));
+                    }
+                }
+                match lan.entry(b.to_string()) {
+                    Entry::Occupied(mut o) => {
+                        o.get_mut().insert(a.to_string());
+                    }
+                    Entry::Vacant(v) => {
+                        v.insert(HashSet::from([a.to_string()]));
+                    }
+                }
+            } else {
+                return Err(ParseError::new(y, 0, "a connection like kh-tc"));
+            }
+        }
+        Ok(Day23 { lan })
+    }
+
+    fn part1(&mut self) -> i64 {
+        let mut triangles: HashSet<(String, String, String)> = HashSet::new();
+        for (k, pairs) in self.lan.iter() {
+            if !k.starts_with('t') {
+                continue;
+            }
+            for a in pairs.iter() {
+                for b in pairs.iter() {
+                    if a == b {
+                        continue;
+                    }
+                    if self.lan[b].contains(a) {
+                        let mut sorted = vec![k.to_string(), a.to_string(), b.to_string()];
+                        sorted.sort();
+                        let tuple = (sorted.remove(0), sorted.remove(0), sorted.remove(0));
+                        triangles.insert(tuple);
+                    }
+                }
+            }
+        }
+        triangles.len() as i64
+    }
+
+    fn part2(&mut self) -> String {
+        let mut groups: Vec<HashSet<String>> = Vec::new();
+        for (k, pairs) in self.lan.iter() {
+            let mut group: HashSet<String> = HashSet::from([k.to_string()]);
+            for p in pairs {
+                if k == p {
+                    continue;
+                }
+                if let Some(pair_pair) = self.lan.get(p) {
+                    if !group.contains(p) && group.is_subset(pair_pair) {
+                        group.insert(p.to_string());
+                    }
+                }
+            }
+            groups.push(group);
+        }
+        groups.sort_by_key(|g| std::cmp::Reverse(g.len()));
+        let first = groups.first().expect("No groups found");
+        let mut ret = first.iter().collect::<Vec<&String>>();
+        ret.sort();
+        ret.into_iter().join(",")
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_example1() {
+        let input = "kh-tc\nqp-kh\nde-cg\nka-co\nyn-aq\nqp-ub\ncg-tb\nvc-aq\ntb-ka\nwh-tc\nyn-cg\nkh-ub\nta-co\nde-co\ntc-td\ntb-wq\nwh-td\nta-ka\ntd-qp\naq-cg\nwq-ub\nub-vc\nde-ta\nwq-aq\nwq-vc\nwh-yn\nka-de\nkh-ta\nco-tc\nwh-qp\ntb-vc\ntd-yn";
+        let mut day = Day23::parse(input).expect("Invalid input");
+        assert!(day.part1() == 7);
+        assert!(day.part2() == "co,de,ka,ta");
+    }
+}
@@ -0,0 +1,123 @@
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+const BLOCK: char = '#';
+const SPACE: char = '.';
+const MAX_HEIGHT: i32 = 5;
+
+pub struct Day25 {
+    locks: Vec<Vec<i32>>,
+    keys: Vec<Vec<i32>>,
+}
+
+/// Whether `key` fits into `lock` without the pin heights overlapping.
+pub fn fits(lock: &[i32], key: &[i32]) -> bool {
+    lock.iter()
+        .zip(key.iter())
+        .all(|(l, k)| l + k <= MAX_HEIGHT)
+}
+
+impl Day25 {
+    /// Pin heights of each lock, column by column.
+    pub fn locks(&self) -> &[Vec<i32>] {
+        &self.locks
+    }
+
+    /// Key heights of each key, column by column.
+    pub fn keys(&self) -> &[Vec<i32>] {
+        &self.keys
+    }
+}
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    type Part1 = i64;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Day25, ParseError> {
+        let mut ret = Day25 {
+            locks: Vec::new(),
+            keys: Vec::new(),
+        };
+        let input = Input::new(input);
+        let sections = input.sections();
+        if sections.is_empty() {
+            return Err(ParseError::new(0, 0, "a lock or key schematic"));
+        }
+        for section in sections {
+            let line_idx = section.line_idx;
+            let shape: Vec<Vec<char>> = section.text.lines().map(|l| l.chars().collect()).collect();
+            for (y, row) in shape.iter().enumerate() {
+                if let Some(x) = row.iter().position(|c| *c != BLOCK && *c != SPACE) {
+                    return Err(ParseError::new(line_idx + y, x, "'#' or '.'"));
+                }
+                if row.len() != shape[0].len() {
+                    return Err(ParseError::new(
+                        line_idx + y,
+                        0,
+                        "a uniform schematic width",
+                    ));
+                }
+            }
+            let height = shape.len();
+            let width = shape[0].len();
+            let mut pattern: Vec<i32> = Vec::new();
+            if shape[0].iter().all(|c| *c == BLOCK) {
+                // lock
+                pattern.extend((0..width).filter_map(|x| {
+                    let y = (1..height).find(|y| shape[*y][x] == SPACE)?;
+                    Some(y as i32 - 1)
+                }));
+                ret.locks.push(pattern);
+            } else {
+                // key
+                pattern.extend((0..width).filter_map(|x| {
+                    let y = (0..height).rev().find(|y| shape[*y][x] == SPACE)?;
+                    Some(height as i32 - y as i32 - 2)
+                }));
+                ret.keys.push(pattern);
+            }
+        }
+        Ok(ret)
+    }
+
+    fn part1(&mut self) -> i64 {
+        let mut ret = 0;
+        for lock in self.locks.iter() {
+            for key in self.keys.iter() {
+                if fits(lock, key) {
+                    ret += 1;
+                }
+            }
+        }
+        ret
+    }
+
+    fn part2(&mut self) -> String {
+        // there is no second puzzle on the last day
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::*;
+    #[test]
+    fn test_example1() {
+        let input = fs::read_to_string("src/day25/dbg.txt").unwrap();
+        let mut day = Day25::parse(&input).expect("Invalid input");
+        assert!(
+            day.keys
+                == vec![
+                    vec![5, 0, 2, 1, 3],
+                    vec![4, 3, 4, 0, 2],
+                    vec![3, 0, 2, 0, 1]
+                ]
+        );
+        assert!(day.locks == vec![vec![0, 5, 3, 4, 3], vec![1, 2, 0, 5, 3]]);
+        assert!(day.part1() == 3);
+    }
+}
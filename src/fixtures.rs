@@ -0,0 +1,144 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::params::{Overrides, Params};
+use crate::verify::KnownAnswers;
+
+/// Extension of the sidecar file holding the expected answers of an example.
+pub const ANSWERS_EXTENSION: &str = "answers";
+/// Extension of the optional sidecar file with the parameters of an example.
+pub const PARAMS_EXTENSION: &str = "params";
+
+/// An example input with its expected answers.
+///
+/// Any `src/dayNN/NAME.txt` next to a `src/dayNN/NAME.answers` is a fixture, the
+/// sidecar uses the `DAY PART ANSWER` lines of `answers.txt`. Parts without a
+/// line are not checked. Examples smaller than the real puzzle set their
+/// parameters in a `NAME.params` file with `DAY NAME VALUE` lines.
+pub struct Fixture {
+    pub day: u32,
+    pub path: PathBuf,
+    pub expected: KnownAnswers,
+    pub params: Params,
+}
+
+impl Fixture {
+    pub fn read_input(&self) -> io::Result<String> {
+        fs::read_to_string(&self.path)
+    }
+}
+
+pub fn day_dir(day: u32) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("src/day{:02}", day))
+}
+
+/// All fixtures of `day`, sorted by file name.
+pub fn discover(day: u32) -> Result<Vec<Fixture>, String> {
+    let dir = day_dir(day);
+    let entries = fs::read_dir(&dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let mut sidecars = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == ANSWERS_EXTENSION))
+        .collect::<Vec<PathBuf>>();
+    sidecars.sort();
+    sidecars
+        .into_iter()
+        .map(|sidecar| load(day, &sidecar))
+        .collect()
+}
+
+fn load(day: u32, sidecar: &Path) -> Result<Fixture, String> {
+    let path = sidecar.with_extension("txt");
+    if !path.is_file() {
+        return Err(format!(
+            "{}: no {} next to it",
+            sidecar.display(),
+            path.display()
+        ));
+    }
+    let expected = KnownAnswers::read(sidecar)?;
+    if expected.get(day, 1).is_none() && expected.get(day, 2).is_none() {
+        return Err(format!("{}: no answers for day {}", sidecar.display(), day));
+    }
+    let params_path = sidecar.with_extension(PARAMS_EXTENSION);
+    let params = match params_path.is_file() {
+        true => Overrides::read(&params_path)?.day(day),
+        false => Params::new(),
+    };
+    Ok(Fixture {
+        day,
+        path,
+        expected,
+        params,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cancel::Token;
+    use crate::solution::{Answers, SolveError, DAYS, PARAMS};
+    use crate::verify::Check;
+    use std::time::Duration;
+
+    /// Runs every fixture of every day, adding a sidecar is enough to get it tested.
+    #[test]
+    fn test_fixtures() {
+        let mut failures = Vec::new();
+        let mut checked = 0;
+        for day in 1..=DAYS.len() as u32 {
+            let fixtures = match discover(day) {
+                Ok(fixtures) => fixtures,
+                Err(e) => {
+                    failures.push(e);
+                    continue;
+                }
+            };
+            for fixture in fixtures {
+                let name = fixture.path.display();
+                let input = fixture.read_input().expect("Fixture vanished");
+                if let Err(e) = fixture.params.check(PARAMS[day as usize - 1]) {
+                    failures.push(format!("{}: {}", name, e));
+                    continue;
+                }
+                let answers =
+                    match DAYS[day as usize - 1](&input, &fixture.params, &Token::unlimited()) {
+                        Ok(answers) => answers,
+                        // examples without a part2 answer may have none to find
+                        Err(SolveError::GaveUp {
+                            part: 2,
+                            part1: Some(part1),
+                            ..
+                        }) if fixture.expected.get(day, 2).is_none() => Answers {
+                            day,
+                            part1,
+                            part2: String::new(),
+                            parse_time: Duration::ZERO,
+                            part1_time: Duration::ZERO,
+                            part2_time: Duration::ZERO,
+                        },
+                        Err(e) => {
+                            failures.push(format!("{}: {}", name, e));
+                            continue;
+                        }
+                    };
+                for (part, check) in fixture.expected.check_answers(&answers).iter().enumerate() {
+                    match check {
+                        Check::Correct => checked += 1,
+                        Check::Wrong { expected } => failures.push(format!(
+                            "{}: part{} is {}, expected {}",
+                            name,
+                            part + 1,
+                            [&answers.part1, &answers.part2][part],
+                            expected
+                        )),
+                        Check::Unknown | Check::Unsolved => {}
+                    }
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+        assert!(checked > 0);
+    }
+}
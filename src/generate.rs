@@ -0,0 +1,1001 @@
+use std::collections::{BTreeSet, HashSet};
+
+use itertools::Itertools;
+
+use crate::utils::search::bfs;
+use crate::utils::{Direction, Grid, PointT, RectT};
+
+type Point = PointT<i64>;
+type Rect = RectT<i64>;
+
+const CLEAR: char = '.';
+const WALL: char = '#';
+const START: char = 'S';
+const END: char = 'E';
+const GUARD: char = '^';
+const CRATE: char = 'O';
+const ROBOT: char = '@';
+const MOVES: [char; 4] = ['^', 'v', '<', '>'];
+const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
+const FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const COLOURS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+/// Characters per line of inputs that are one long text in the real puzzle.
+const LINE_LENGTH: usize = 70;
+
+/// Seeded pseudo random numbers (SplitMix64). Inputs only have to be reproducible,
+/// the same seed always gives the same input.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `min..=max`, the slight bias of the modulo does not matter here.
+    pub fn range(&mut self, min: i64, max: i64) -> i64 {
+        assert!(min <= max, "Empty range {}..={}", min, max);
+        let span = max.abs_diff(min).wrapping_add(1);
+        match span {
+            0 => self.next_u64() as i64,
+            _ => min.wrapping_add((self.next_u64() % span) as i64),
+        }
+    }
+
+    /// Uniform in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    fn point(&mut self, bounds: &Rect) -> Point {
+        Point {
+            x: self.range(bounds.x, bounds.x + bounds.width - 1),
+            y: self.range(bounds.y, bounds.y + bounds.height - 1),
+        }
+    }
+}
+
+/// A generated input together with the part 2 answer planted in it.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Planted {
+    pub input: String,
+    pub part2: String,
+}
+
+/// Makes a valid input of one day from random numbers. `size` scales it, it is a
+/// number of lines, the side of a grid, ... as each generator describes.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// The generator of every day with the size of the real inputs, `GENERATORS[0]`
+/// is day 1.
+pub const GENERATORS: [(Generator, usize); 25] = [
+    (location_lists, 1000),
+    (reports, 1000),
+    (memory, 700),
+    (word_search, 140),
+    (print_queue, 200),
+    (lab, 130),
+    (equations, 850),
+    (antennas, 50),
+    (disk_map, 10000),
+    (topographic_map, 50),
+    (stones, 8),
+    (garden, 140),
+    (claw_machines, 320),
+    (robots, 500),
+    (warehouse, 50),
+    (reindeer_maze, 141),
+    (computer, 16),
+    (byte_drops, 2000),
+    (towels, 400),
+    (race_track, 141),
+    (door_codes, 5),
+    (secrets, 2000),
+    (lan, 520),
+    (adder, 45),
+    (schematics, 500),
+];
+
+/// The input of `day` for `seed`, `size` defaults to the size of the real input.
+pub fn generate(day: u32, seed: u64, size: Option<usize>) -> Option<String> {
+    let (generator, default_size) = GENERATORS.get((day as usize).checked_sub(1)?)?;
+    Some(generator(
+        &mut Rng::new(seed),
+        size.unwrap_or(*default_size),
+    ))
+}
+
+fn square(size: usize) -> Rect {
+    Rect {
+        x: 0,
+        y: 0,
+        width: size as i64,
+        height: size as i64,
+    }
+}
+
+/// Grids are written without the newline after the last row, like all other inputs.
+fn grid_text(grid: &Grid<char>) -> String {
+    grid.to_string().trim_end().to_string()
+}
+
+/// `size` pairs of location IDs, about a third of the right list is copied from the left.
+pub fn location_lists(rng: &mut Rng, size: usize) -> String {
+    let left = (0..size)
+        .map(|_| rng.range(10000, 99999))
+        .collect::<Vec<i64>>();
+    left.iter()
+        .map(|l| {
+            let r = match rng.chance(0.3) {
+                true => *rng.choose(&left),
+                false => rng.range(10000, 99999),
+            };
+            format!("{}   {}", l, r)
+        })
+        .join("\n")
+}
+
+/// `size` reports of 5 to 8 levels that change steadily, half of them have one bad level.
+pub fn reports(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let sign = if rng.chance(0.5) { 1 } else { -1 };
+            let mut level = if sign > 0 {
+                rng.range(1, 50)
+            } else {
+                rng.range(40, 90)
+            };
+            let mut levels = vec![level];
+            for _ in 1..rng.range(5, 8) {
+                level += sign * rng.range(1, 3);
+                levels.push(level);
+            }
+            if rng.chance(0.5) {
+                let idx = rng.below(levels.len());
+                levels[idx] = rng.range(1, 99);
+            }
+            levels.iter().join(" ")
+        })
+        .join("\n")
+}
+
+/// Corrupted memory with `size` instructions, broken ones and noise in between.
+pub fn memory(rng: &mut Rng, size: usize) -> String {
+    const NOISE: [&str; 12] = [
+        "", "!", "@", "#", "%", "^", "&", "*", " ", "from()", "mul(", "what()",
+    ];
+    let mut ret = String::new();
+    for _ in 0..size {
+        let (a, b) = (rng.range(1, 999), rng.range(1, 999));
+        let instruction = match rng.below(8) {
+            0 => "do()".to_string(),
+            1 => "don't()".to_string(),
+            2 => format!("mul({}, {})", a, b),
+            3 => format!("mul[{},{}]", a, b),
+            4 => format!("mul({},{}!", a, b),
+            _ => format!("mul({},{})", a, b),
+        };
+        let noise = rng.choose(&NOISE);
+        ret.push_str(noise);
+        ret.push_str(&instruction);
+        if ret.len() - ret.rfind('\n').map_or(0, |idx| idx + 1) > LINE_LENGTH {
+            ret.push('\n');
+        }
+    }
+    ret.trim_end().to_string()
+}
+
+/// A `size` by `size` word search of the letters of XMAS.
+pub fn word_search(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| (0..size).map(|_| *rng.choose(&XMAS)).collect::<String>())
+        .join("\n")
+}
+
+/// Rules that order every pair of 49 pages and `size` updates of 5 to 23 of them,
+/// about half of the updates are in the right order.
+pub fn print_queue(rng: &mut Rng, size: usize) -> String {
+    let mut order = (10..=99).collect::<Vec<i64>>();
+    rng.shuffle(&mut order);
+    order.truncate(49);
+    let mut rules = order
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| format!("{}|{}", a, b))
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut rules);
+    let updates = (0..size)
+        .map(|_| {
+            let mut pages = (0..order.len()).collect::<Vec<usize>>();
+            rng.shuffle(&mut pages);
+            pages.truncate(2 * rng.range(2, 11) as usize + 1);
+            if rng.chance(0.5) {
+                pages.sort();
+            }
+            pages.iter().map(|idx| order[*idx]).join(",")
+        })
+        .collect::<Vec<String>>();
+    format!("{}\n\n{}", rules.join("\n"), updates.join("\n"))
+}
+
+/// Walks the guard of day 6, `false` if it ends up in a loop.
+fn guard_leaves(grid: &Grid<char>, start: Point) -> bool {
+    let mut seen = HashSet::new();
+    let (mut pos, mut dir) = (start, Direction::North);
+    while seen.insert((pos, dir)) {
+        let next = pos.step(dir);
+        match grid.get(&next) {
+            None => return true,
+            Some(&WALL) => dir = dir.turn_right(),
+            Some(_) => pos = next,
+        }
+    }
+    false
+}
+
+/// A `size` by `size` lab with some obstacles and a guard that walks out of it.
+pub fn lab(rng: &mut Rng, size: usize) -> String {
+    let bounds = square(size.max(1));
+    loop {
+        let mut grid = Grid::new(size.max(1), size.max(1), CLEAR);
+        for p in bounds.points() {
+            if rng.chance(0.08) {
+                grid[&p] = WALL;
+            }
+        }
+        let start = rng.point(&bounds);
+        grid[&start] = GUARD;
+        if guard_leaves(&grid, start) {
+            return grid_text(&grid);
+        }
+    }
+}
+
+/// `size` calibration equations of 3 to 9 numbers, about half of them can be solved.
+/// The results stay far below `i64::MAX` even if all numbers are concatenated.
+pub fn equations(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let numbers = (0..rng.range(3, 9))
+                .map(|_| rng.range(1, 99))
+                .collect::<Vec<i64>>();
+            let mut result = numbers[0];
+            for n in numbers[1..].iter() {
+                result = match rng.below(3) {
+                    0 => result + n,
+                    1 => result * n,
+                    _ => result * if *n < 10 { 10 } else { 100 } + n,
+                };
+            }
+            if rng.chance(0.5) {
+                result += 1;
+            }
+            format!("{}: {}", result, numbers.iter().join(" "))
+        })
+        .join("\n")
+}
+
+/// A `size` by `size` map with 3 or 4 antennas of each of `size / 4` frequencies.
+pub fn antennas(rng: &mut Rng, size: usize) -> String {
+    let mut grid = Grid::new(size, size, CLEAR);
+    let mut free = square(size).points().collect::<Vec<Point>>();
+    rng.shuffle(&mut free);
+    for frequency in FREQUENCIES.chars().take((size / 4).max(1)) {
+        for _ in 0..rng.range(3, 4) {
+            if let Some(p) = free.pop() {
+                grid[&p] = frequency;
+            }
+        }
+    }
+    grid_text(&grid)
+}
+
+/// A disk map of `size` files with up to 9 free blocks after all but the last.
+pub fn disk_map(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|idx| match idx + 1 < size {
+            true => format!("{}{}", rng.range(1, 9), rng.range(0, 9)),
+            false => rng.range(1, 9).to_string(),
+        })
+        .collect()
+}
+
+/// A `size` by `size` map of random heights with `size` hiking trails walked into it.
+pub fn topographic_map(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let bounds = square(size);
+    let mut grid = Grid::new(size, size, '0');
+    for p in bounds.points() {
+        grid[&p] = char::from(b'0' + rng.below(10) as u8);
+    }
+    for _ in 0..size {
+        let mut p = rng.point(&bounds);
+        let mut dir = *rng.choose(&Direction::CARDINAL);
+        for height in b'0'..=b'9' {
+            grid[&p] = char::from(height);
+            dir = *rng.choose(&[dir, dir.turn_left(), dir.turn_right()]);
+            if !grid.contains(&p.step(dir)) {
+                dir = dir.reverse();
+            }
+            p = p.step(dir);
+        }
+    }
+    grid_text(&grid)
+}
+
+/// `size` stones with numbers of up to 6 digits.
+pub fn stones(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| rng.range(0, 999_999)).join(" ")
+}
+
+/// A `size` by `size` garden of regions around random seeds, each plot belongs to
+/// the closest seed. Neighbouring regions can grow the same plant.
+pub fn garden(rng: &mut Rng, size: usize) -> String {
+    let bounds = square(size);
+    let seeds = (0..(size * size / 16).max(1))
+        .map(|_| (rng.point(&bounds), char::from(b'A' + rng.below(26) as u8)))
+        .collect::<Vec<(Point, char)>>();
+    let mut grid = Grid::new(size, size, CLEAR);
+    for p in bounds.points() {
+        if let Some((_, plant)) = seeds.iter().min_by_key(|(seed, _)| seed.distance(&p)) {
+            grid[&p] = *plant;
+        }
+    }
+    grid_text(&grid)
+}
+
+/// `size` claw machines, about half of them can win their prize.
+pub fn claw_machines(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (a, b) = loop {
+                let [a, b] = [(); 2].map(|_| Point {
+                    x: rng.range(10, 99),
+                    y: rng.range(10, 99),
+                });
+                // the buttons of the real machines never move in the same direction
+                if a.x * b.y != a.y * b.x {
+                    break (a, b);
+                }
+            };
+            let prize = match rng.chance(0.5) {
+                true => a * rng.range(0, 100) + b * rng.range(0, 100),
+                false => Point {
+                    x: rng.range(1000, 20000),
+                    y: rng.range(1000, 20000),
+                },
+            };
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+                a.x, a.y, b.x, b.y, prize.x, prize.y
+            )
+        })
+        .join("\n\n")
+}
+
+/// `count` robots on the floor of day 14, after `second` seconds two thirds of them
+/// form a filled triangle and the rest are scattered around it. At most a fifth
+/// of the floor is covered, that leaves room for the triangle and keeps robots
+/// scattered at random from looking like a tree at other seconds.
+pub fn robots_with_tree(rng: &mut Rng, count: usize, second: i64) -> Planted {
+    let bounds = crate::day14::BOUNDS;
+    let count = count.min(bounds.area() as usize / 5);
+    let tree = count * 2 / 3;
+    let rows = (tree as f64).sqrt().ceil() as i64;
+    let top = Point {
+        x: rng.range(rows, bounds.width - rows - 1),
+        y: rng.range(0, bounds.height - rows - 1),
+    };
+    let mut positions = (0..rows)
+        .flat_map(|y| (-y..=y).map(move |x| top + Point { x, y }))
+        .take(tree)
+        .collect::<Vec<Point>>();
+    while positions.len() < count {
+        positions.push(rng.point(&bounds));
+    }
+    let mut velocity = || match rng.range(-99, 98) {
+        0 => 99,
+        v => v,
+    };
+    let input = positions
+        .iter()
+        .map(|p| {
+            let v = Point {
+                x: velocity(),
+                y: velocity(),
+            };
+            let start = bounds.wrap(&(*p - v * second));
+            format!("p={},{} v={},{}", start.x, start.y, v.x, v.y)
+        })
+        .join("\n");
+    Planted {
+        input,
+        part2: second.to_string(),
+    }
+}
+
+/// `size` robots that show a tree at a random second before the pattern repeats.
+pub fn robots(rng: &mut Rng, size: usize) -> String {
+    let bounds = crate::day14::BOUNDS;
+    let second = rng.range(1, bounds.area() - 1);
+    robots_with_tree(rng, size, second).input
+}
+
+/// A `size` by `size` warehouse with walls all around, crates, a robot and
+/// `size * size` moves.
+pub fn warehouse(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let inner = Rect {
+        x: 1,
+        y: 1,
+        width: size as i64 - 2,
+        height: size as i64 - 2,
+    };
+    let mut grid = Grid::new(size, size, WALL);
+    for p in inner.points() {
+        grid[&p] = match rng.below(20) {
+            0 => WALL,
+            1..=5 => CRATE,
+            _ => CLEAR,
+        };
+    }
+    grid[&rng.point(&inner)] = ROBOT;
+    let moves = (0..size * size)
+        .map(|_| *rng.choose(&MOVES))
+        .collect::<Vec<char>>();
+    let moves = moves.chunks(LINE_LENGTH).map(String::from_iter).join("\n");
+    format!("{}\n\n{}", grid_text(&grid), moves)
+}
+
+/// A maze with walls all around, `S` in the bottom left and `E` in the top right
+/// corner. The corridors are dug by a random depth first search, so there is exactly
+/// one path between two tiles. Each wall between two corridors is then knocked out
+/// with probability `loops`. Sizes are rounded down to odd numbers of at least 5.
+pub fn maze(rng: &mut Rng, width: usize, height: usize, loops: f64) -> Grid<char> {
+    let odd = |n: usize| (n.max(5) - 1) / 2 * 2 + 1;
+    let (width, height) = (odd(width), odd(height));
+    let mut grid = Grid::new(width, height, WALL);
+    let start = Point {
+        x: 1,
+        y: height as i64 - 2,
+    };
+    grid[&start] = CLEAR;
+    let mut stack = vec![start];
+    while let Some(p) = stack.last().copied() {
+        let mut dirs = Direction::CARDINAL;
+        rng.shuffle(&mut dirs);
+        let next = dirs
+            .iter()
+            .map(|d| (p.step(*d), p.step(*d).step(*d)))
+            .find(|(_, n)| grid.get(n) == Some(&WALL));
+        match next {
+            Some((wall, n)) => {
+                grid[&wall] = CLEAR;
+                grid[&n] = CLEAR;
+                stack.push(n);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    let inner = Rect {
+        x: 1,
+        y: 1,
+        width: width as i64 - 2,
+        height: height as i64 - 2,
+    };
+    for p in inner.points() {
+        if (p.x + p.y) % 2 == 1 && grid[&p] == WALL && rng.chance(loops) {
+            grid[&p] = CLEAR;
+        }
+    }
+    grid[&start] = START;
+    grid[&Point {
+        x: width as i64 - 2,
+        y: 1,
+    }] = END;
+    grid
+}
+
+/// A `size` by `size` maze where a tenth of the inner walls are knocked out, so there
+/// are several paths to choose from.
+pub fn reindeer_maze(rng: &mut Rng, size: usize) -> String {
+    grid_text(&maze(rng, size, size, 0.1))
+}
+
+/// Output of the programs of `computer_with_quine` for register A `a`.
+fn outputs(mut a: u64, k1: u64, k2: u64) -> Vec<u64> {
+    let mut ret = Vec::new();
+    loop {
+        let b = (a & 7) ^ k1;
+        ret.push((b ^ k2 ^ (a >> b)) & 7);
+        a >>= 3;
+        if a == 0 {
+            return ret;
+        }
+    }
+}
+
+/// Smallest register A that outputs `program`, found three bits at a time from the
+/// end of the program. `prefix` outputs the last `matched` numbers.
+fn find_quine(program: &[u64], k1: u64, k2: u64, prefix: u64, matched: usize) -> Option<u64> {
+    if matched == program.len() {
+        return Some(prefix);
+    }
+    (0..8)
+        .map(|bits| prefix << 3 | bits)
+        .filter(|a| *a != 0 && outputs(*a, k1, k2) == program[program.len() - matched - 1..])
+        .find_map(|a| find_quine(program, k1, k2, a, matched + 1))
+}
+
+/// A program for the computer of day 17 in the shape of the real ones: every round
+/// outputs a mix of the lowest bits of A, then A is shifted by 3 bits until it is 0.
+/// The constants are drawn until the program can output itself, the smallest such A
+/// is the planted answer. Register A starts with `digits` random octal digits.
+pub fn computer_with_quine(rng: &mut Rng, digits: usize) -> Planted {
+    loop {
+        let (k1, k2, k3) = (rng.range(1, 7), rng.range(1, 7), rng.range(0, 7));
+        if k1 == k2 {
+            // the program would print its last number for A = 0
+            continue;
+        }
+        let program = match rng.chance(0.5) {
+            true => [2, 4, 1, k1, 7, 5, 1, k2, 4, k3, 5, 5, 0, 3, 3, 0],
+            false => [2, 4, 1, k1, 7, 5, 4, k3, 1, k2, 5, 5, 0, 3, 3, 0],
+        };
+        let code = program.map(|n| n as u64);
+        let Some(quine) = find_quine(&code, k1 as u64, k2 as u64, 0, 0) else {
+            continue;
+        };
+        let digits = digits.clamp(1, 20) as u32;
+        let a = rng.range(8_i64.pow(digits - 1), 8_i64.pow(digits) - 1);
+        return Planted {
+            input: format!(
+                "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}",
+                a,
+                program.iter().join(",")
+            ),
+            part2: quine.to_string(),
+        };
+    }
+}
+
+/// A program that outputs itself, register A has `size` octal digits.
+pub fn computer(rng: &mut Rng, size: usize) -> String {
+    computer_with_quine(rng, size).input
+}
+
+/// Bytes falling onto the memory space of day 18. The first ones miss a random path
+/// from the top left to the bottom right corner, so that part 1 can be solved. Then a
+/// whole row is corrupted in random order, mixed with `size` other bytes.
+pub fn byte_drops(rng: &mut Rng, size: usize) -> String {
+    let bounds = crate::day18::BOUNDS;
+    let (start, end) = (bounds.min(), bounds.max() - Point { x: 1, y: 1 });
+    let mut path = BTreeSet::from([start]);
+    let mut p = start;
+    while p != end {
+        let right = p.y == end.y || (p.x != end.x && rng.chance(0.5));
+        p += if right {
+            Point { x: 1, y: 0 }
+        } else {
+            Point { x: 0, y: 1 }
+        };
+        path.insert(p);
+    }
+    let row = rng.range(1, bounds.height - 2);
+    let mut cells = bounds
+        .points()
+        .filter(|p| !path.contains(p) && p.y != row)
+        .collect::<Vec<Point>>();
+    rng.shuffle(&mut cells);
+    let rest = cells.split_off(crate::day18::DROPS.min(cells.len()));
+    let mut later = bounds
+        .points()
+        .filter(|p| p.y == row)
+        .chain(rest.into_iter().take(size))
+        .collect::<Vec<Point>>();
+    rng.shuffle(&mut later);
+    cells
+        .iter()
+        .chain(later.iter())
+        .map(|p| format!("{},{}", p.x, p.y))
+        .join("\n")
+}
+
+/// 100 towel patterns of 1 to 8 stripes and `size` designs, half of them made from the
+/// towels. There is no towel with a single green stripe, so random designs with one
+/// are often impossible.
+pub fn towels(rng: &mut Rng, size: usize) -> String {
+    let mut patterns: Vec<String> = COLOURS[..4].iter().map(|c| c.to_string()).collect();
+    while patterns.len() < 100 {
+        let pattern = (0..rng.range(2, 8))
+            .map(|_| *rng.choose(&COLOURS))
+            .collect::<String>();
+        if !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+    rng.shuffle(&mut patterns);
+    let designs = (0..size)
+        .map(|_| {
+            let length = rng.range(20, 50) as usize;
+            let mut design = String::new();
+            while design.len() < length {
+                match rng.chance(0.5) {
+                    true => design.push_str(rng.choose(&patterns).as_str()),
+                    false => design.push(*rng.choose(&COLOURS)),
+                }
+            }
+            design
+        })
+        .collect::<Vec<String>>();
+    format!("{}\n\n{}", patterns.join(", "), designs.join("\n"))
+}
+
+/// A single track through a `size` by `size` maze: the path from `S` to `E` of a maze
+/// without loops, all other tiles are walls.
+pub fn race_track(rng: &mut Rng, size: usize) -> String {
+    let grid = maze(rng, size, size, 0.0);
+    let (Some(start), Some(end)) = (grid.find::<i64>(&START), grid.find::<i64>(&END)) else {
+        unreachable!("Mazes have a start and an end");
+    };
+    let paths = bfs(start, |p| {
+        grid.neighbours(p)
+            .filter(|n| grid[n] != WALL)
+            .collect::<Vec<Point>>()
+    });
+    let track = paths.path(&end).expect("Mazes are connected");
+    let mut ret = grid.map(|_| WALL);
+    for p in track.iter() {
+        ret[p] = CLEAR;
+    }
+    ret[&start] = START;
+    ret[&end] = END;
+    grid_text(&ret)
+}
+
+/// `size` door codes of three digits followed by `A`.
+pub fn door_codes(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{:03}A", rng.range(0, 999)))
+        .join("\n")
+}
+
+/// The initial secret numbers of `size` buyers.
+pub fn secrets(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| rng.range(1, (1 << 24) - 1)).join("\n")
+}
+
+/// A network of `computers` computers named by two letters. `clique` of them are
+/// connected to each other and to one other computer each, the rest are linked at
+/// random with three connections per computer on average. The planted group is the
+/// largest one as long as it has more than four members.
+pub fn lan_with_clique(rng: &mut Rng, computers: usize, clique: usize) -> Planted {
+    let letters = || 'a'..='z';
+    let mut names = letters()
+        .cartesian_product(letters())
+        .map(|(a, b)| format!("{}{}", a, b))
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut names);
+    names.truncate(computers.clamp(2, names.len()));
+    let (members, others) = names.split_at(clique.min(names.len()));
+    let mut connections = BTreeSet::new();
+    for (idx, a) in members.iter().enumerate() {
+        for b in members[idx + 1..].iter() {
+            connections.insert((a, b));
+        }
+        if let Some(b) = others.get(idx) {
+            connections.insert((a, b));
+        }
+    }
+    if others.len() > 1 {
+        for _ in 0..others.len() * 3 / 2 {
+            let (a, b) = (rng.choose(others), rng.choose(others));
+            if a != b {
+                connections.insert((a.min(b), a.max(b)));
+            }
+        }
+    }
+    let mut lines = connections
+        .into_iter()
+        .map(|(a, b)| match rng.chance(0.5) {
+            true => format!("{}-{}", a, b),
+            false => format!("{}-{}", b, a),
+        })
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut lines);
+    Planted {
+        input: lines.join("\n"),
+        part2: members.iter().sorted().join(","),
+    }
+}
+
+/// A network of `size` computers with a group of 13.
+pub fn lan(rng: &mut Rng, size: usize) -> String {
+    lan_with_clique(rng, size, 13).input
+}
+
+/// `a OP b -> out` of day 24.
+struct Gate {
+    a: String,
+    op: &'static str,
+    b: String,
+    out: String,
+}
+
+/// Whether every wire of `gates` gets a level, swapped outputs can form a cycle.
+fn settles(gates: &[Gate]) -> bool {
+    let mut known = HashSet::new();
+    let mut pending = gates.iter().collect::<Vec<&Gate>>();
+    loop {
+        let before = pending.len();
+        pending.retain(|gate| {
+            let inputs = [&gate.a, &gate.b]
+                .map(|wire| wire.starts_with(['x', 'y']) || known.contains(wire.as_str()));
+            if inputs == [true, true] {
+                known.insert(gate.out.as_str());
+                return false;
+            }
+            true
+        });
+        if pending.is_empty() || pending.len() == before {
+            return pending.is_empty();
+        }
+    }
+}
+
+/// A ripple carry adder for two `bits` wide numbers, built like the circuit of day 24
+/// with random names for the inner wires. Then the outputs of `swaps` pairs of gates
+/// are exchanged, only swaps that keep the circuit free of cycles are planted. The
+/// sorted swapped wires are the planted answer.
+pub fn adder_with_swaps(rng: &mut Rng, bits: usize, swaps: usize) -> Planted {
+    let bits = bits.clamp(2, 64);
+    let mut used = HashSet::new();
+    let mut wire = || loop {
+        let name = [rng.range(0, 22), rng.range(0, 25), rng.range(0, 25)]
+            .map(|c| char::from(b'a' + c as u8))
+            .iter()
+            .collect::<String>();
+        if used.insert(name.clone()) {
+            return name;
+        }
+    };
+    let gate = |a: &String, op, b: &String, out: &String| Gate {
+        a: a.clone(),
+        op,
+        b: b.clone(),
+        out: out.clone(),
+    };
+    let mut gates = Vec::new();
+    let mut carry = String::new();
+    for bit in 0..bits {
+        let [x, y, z] = ['x', 'y', 'z'].map(|c| format!("{}{:02}", c, bit));
+        if bit == 0 {
+            carry = wire();
+            gates.push(gate(&x, "XOR", &y, &z));
+            gates.push(gate(&x, "AND", &y, &carry));
+            continue;
+        }
+        let (sum, direct, via) = (wire(), wire(), wire());
+        let next = match bit + 1 == bits {
+            true => format!("z{:02}", bits),
+            false => wire(),
+        };
+        gates.push(gate(&x, "XOR", &y, &sum));
+        gates.push(gate(&x, "AND", &y, &direct));
+        gates.push(gate(&sum, "XOR", &carry, &z));
+        gates.push(gate(&sum, "AND", &carry, &via));
+        gates.push(gate(&direct, "OR", &via, &next));
+        carry = next;
+    }
+    let swaps = swaps.min(gates.len() / 2);
+    let swapped = loop {
+        let mut picked = (0..gates.len()).collect::<Vec<usize>>();
+        rng.shuffle(&mut picked);
+        picked.truncate(2 * swaps);
+        let exchange = |gates: &mut Vec<Gate>| {
+            for pair in picked.chunks(2) {
+                let out = gates[pair[0]].out.clone();
+                gates[pair[0]].out = std::mem::replace(&mut gates[pair[1]].out, out);
+            }
+        };
+        exchange(&mut gates);
+        if settles(&gates) {
+            break picked
+                .iter()
+                .map(|idx| gates[*idx].out.clone())
+                .sorted()
+                .collect::<Vec<String>>();
+        }
+        exchange(&mut gates);
+    };
+    rng.shuffle(&mut gates);
+    let levels = ['x', 'y']
+        .iter()
+        .flat_map(|c| (0..bits).map(move |bit| format!("{}{:02}", c, bit)))
+        .map(|name| format!("{}: {}", name, rng.below(2)))
+        .collect::<Vec<String>>();
+    let gates = gates
+        .iter()
+        .map(|g| match rng.chance(0.5) {
+            true => format!("{} {} {} -> {}", g.a, g.op, g.b, g.out),
+            false => format!("{} {} {} -> {}", g.b, g.op, g.a, g.out),
+        })
+        .collect::<Vec<String>>();
+    Planted {
+        input: format!("{}\n\n{}", levels.join("\n"), gates.join("\n")),
+        part2: swapped.join(","),
+    }
+}
+
+/// An adder for `size` bit numbers with four pairs of swapped outputs.
+pub fn adder(rng: &mut Rng, size: usize) -> String {
+    adder_with_swaps(rng, size, 4).input
+}
+
+/// `size` lock and key schematics of five pins.
+pub fn schematics(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let heights = [(); 5].map(|_| rng.range(0, 5));
+            let lock = rng.chance(0.5);
+            (0..7)
+                .map(|row| {
+                    heights
+                        .iter()
+                        .map(|height| match lock {
+                            true if row <= *height => WALL,
+                            false if 6 - row <= *height => WALL,
+                            _ => CLEAR,
+                        })
+                        .collect::<String>()
+                })
+                .join("\n")
+        })
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cancel::Token;
+    use crate::params::Params;
+    use crate::solution::{Answer, Solution, DAYS};
+
+    #[test]
+    fn test_rng() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..8).map(|_| rng.next_u64()).collect::<Vec<u64>>()
+        };
+        assert!(numbers(1) == numbers(1));
+        assert!(numbers(1) != numbers(2));
+
+        let mut rng = Rng::new(3);
+        assert!((0..1000)
+            .map(|_| rng.range(-2, 2))
+            .all(|n| (-2..=2).contains(&n)));
+        assert!(rng.range(i64::MIN, i64::MAX) != rng.range(i64::MIN, i64::MAX));
+        let mut items = (0..20).collect::<Vec<i32>>();
+        rng.shuffle(&mut items);
+        assert!(items != (0..20).collect::<Vec<i32>>());
+        assert!(items.iter().sorted().copied().eq(0..20));
+    }
+
+    #[test]
+    fn test_all_days() {
+        let sizes = [
+            20, 20, 30, 10, 10, 10, 20, 12, 20, 10, 3, 10, 10, 30, 8, 15, 6, 50, 10, 21, 5, 5, 40,
+            6, 10,
+        ];
+        for (idx, size) in sizes.iter().enumerate() {
+            let day = idx as u32 + 1;
+            let input = generate(day, 7, Some(*size)).expect("Every day has a generator");
+            assert!(input == generate(day, 7, Some(*size)).expect("Same input"));
+            if day == 15 {
+                // work in progress, only the parser is done
+                assert!(crate::day15::Day15::parse(&input).is_ok());
+                continue;
+            }
+            if let Err(e) = DAYS[idx](&input, &Params::new(), &Token::unlimited()) {
+                panic!("Day {}: {}", day, e);
+            }
+        }
+        assert!(generate(0, 7, None).is_none());
+        assert!(generate(26, 7, None).is_none());
+    }
+
+    #[test]
+    fn test_maze() {
+        let mut rng = Rng::new(5);
+        let grid = maze(&mut rng, 12, 9, 0.0);
+        assert!(grid.width() == 11 && grid.height() == 9);
+        assert!(grid[&Point { x: 1, y: 7 }] == START);
+        assert!(grid[&Point { x: 9, y: 1 }] == END);
+        // a maze without loops is a tree: one passage less than it has tiles
+        let tiles = grid.positions::<i64, _>(|c| *c != WALL).collect::<Vec<_>>();
+        let passages = tiles
+            .iter()
+            .flat_map(|p| {
+                p.neighbours()
+                    .filter(|n| grid.get(n).is_some_and(|c| *c != WALL))
+            })
+            .count()
+            / 2;
+        assert!(passages + 1 == tiles.len());
+        let paths = bfs(tiles[0], |p| {
+            grid.neighbours(p)
+                .filter(|n| grid[n] != WALL)
+                .collect::<Vec<Point>>()
+        });
+        assert!(tiles.iter().all(|p| paths.distance(p).is_some()));
+    }
+
+    #[test]
+    fn test_planted() {
+        let mut rng = Rng::new(11);
+        let planted = robots_with_tree(&mut rng, 60, 271);
+        let mut day = crate::day14::Day14::parse(&planted.input).expect("Invalid input");
+        assert!(day.part2().answer() == Some(planted.part2));
+        let planted = robots_with_tree(&mut rng, 20000, 271);
+        assert!(planted.input.lines().count() == 2080);
+        let mut day = crate::day14::Day14::parse(&planted.input).expect("Invalid input");
+        assert!(day.part2().answer() == Some(planted.part2));
+
+        let planted = lan_with_clique(&mut rng, 60, 8);
+        let mut day = crate::day23::Day23::parse(&planted.input).expect("Invalid input");
+        assert!(day.part2() == planted.part2);
+
+        for _ in 0..5 {
+            let planted = computer_with_quine(&mut rng, 8);
+            let mut day = crate::day17::Day17::parse(&planted.input).expect("Invalid input");
+            assert!(day.part2().answer() == Some(planted.part2));
+        }
+
+        let planted = adder_with_swaps(&mut rng, 10, 0);
+        assert!(planted.part2.is_empty());
+        let mut day = crate::day24::Day24::parse(&planted.input).expect("Invalid input");
+        let level = |c| {
+            (0..10)
+                .map(|bit| format!("{}{:02}: ", c, bit))
+                .map(|name| planted.input.contains(&(name + "1")) as i64)
+                .rev()
+                .fold(0, |acc, bit| acc << 1 | bit)
+        };
+        assert!(day.part1() == Some(level('x') + level('y')));
+
+        let planted = adder_with_swaps(&mut rng, 10, 4);
+        let wires = planted.part2.split(',').collect::<HashSet<&str>>();
+        assert!(wires.len() == 8);
+        let mut day = crate::day24::Day24::parse(&planted.input).expect("Invalid input");
+        day.part1();
+    }
+}
@@ -0,0 +1,392 @@
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::bench::{Bench, Stats};
+use crate::parse::{parse_in_line, ParseError};
+
+/// The measured phases of a day, in the order of `Bench::samples`.
+pub const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// One-sided 1% quantile of the standard normal distribution, a larger
+/// `Comparison::z` is a significant slowdown.
+pub const Z_CRITICAL: f64 = 2.326;
+/// Smaller relative changes of the median are not reported, however
+/// significant they are.
+pub const MIN_CHANGE: f64 = 0.05;
+/// Fewer samples on either side are not compared.
+pub const MIN_SAMPLES: usize = 5;
+
+/// In the crate root, next to `answers.txt`, so `cargo clean` keeps it.
+pub fn default_history_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("bench_history.txt")
+}
+
+/// Asks git for the abbreviated hash of `rev` in the crate directory.
+fn rev_parse(rev: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short=12", "--verify", "--quiet", rev])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()?;
+    let hash = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !hash.is_empty()).then_some(hash)
+}
+
+/// The checked out commit, with `-dirty` when there are uncommitted changes
+/// and `unknown` outside a git checkout.
+pub fn current_commit() -> String {
+    let Some(hash) = rev_parse("HEAD") else {
+        return "unknown".to_string();
+    };
+    let dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty());
+    match dirty {
+        true => format!("{}-dirty", hash),
+        false => hash,
+    }
+}
+
+/// Turns a git revision like `HEAD~1` or `main` into the hash the history
+/// uses, anything git does not know is kept as it is.
+pub fn resolve_commit(rev: &str) -> String {
+    match rev.strip_suffix("-dirty") {
+        Some(rev) => format!("{}-dirty", rev_parse(rev).unwrap_or(rev.to_string())),
+        None => rev_parse(rev).unwrap_or(rev.to_string()),
+    }
+}
+
+/// Whether `commit` of the history is the one meant by `rev`, which may be
+/// shorter. The working tree of a commit is not the commit itself.
+fn same_commit(commit: &str, rev: &str) -> bool {
+    fn split(s: &str) -> (&str, bool) {
+        s.strip_suffix("-dirty").map_or((s, false), |s| (s, true))
+    }
+    let ((commit, dirty), (rev, rev_dirty)) = (split(commit), split(rev));
+    dirty == rev_dirty && !rev.is_empty() && commit.starts_with(rev)
+}
+
+/// The samples of one phase of one day measured at `commit`.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Entry {
+    pub commit: String,
+    pub day: u32,
+    pub phase: &'static str,
+    pub samples: Vec<Duration>,
+}
+
+impl Entry {
+    /// An entry for every phase of `bench`.
+    pub fn from_bench(commit: &str, bench: &Bench) -> Vec<Entry> {
+        PHASES
+            .iter()
+            .zip(bench.samples.iter())
+            .map(|(phase, samples)| Entry {
+                commit: commit.to_string(),
+                day: bench.answers.day,
+                phase,
+                samples: samples.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.commit, self.day, self.phase)?;
+        for sample in self.samples.iter() {
+            write!(f, " {}", sample.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+/// All benchmark results so far, from a file with one `COMMIT DAY PHASE NS...`
+/// entry per line that `aoc bench` appends to. Runs of the same commit and
+/// day add to each other's samples.
+#[derive(PartialEq, Eq, Debug, Default)]
+pub struct History {
+    pub entries: Vec<Entry>,
+}
+
+impl History {
+    /// `#` starts a comment line.
+    pub fn parse(input: &str) -> Result<History, ParseError> {
+        let mut entries = Vec::new();
+        for (y, line) in input.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let (Some(commit), Some(day), Some(phase)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(ParseError::new(y, line.len(), "COMMIT DAY PHASE NS..."));
+            };
+            let day: u32 = parse_in_line(y, line, day, "a day")?;
+            let Some(phase) = PHASES.iter().find(|p| **p == phase) else {
+                return Err(ParseError::in_line(y, line, phase, "parse, part1 or part2"));
+            };
+            let samples = fields
+                .map(|ns| parse_in_line(y, line, ns, "nanoseconds").map(Duration::from_nanos))
+                .collect::<Result<Vec<Duration>, ParseError>>()?;
+            entries.push(Entry {
+                commit: commit.to_string(),
+                day,
+                phase,
+                samples,
+            });
+        }
+        Ok(History { entries })
+    }
+
+    /// Reads the history at `path`, which is empty when there is no file yet.
+    pub fn read(path: &Path) -> Result<History, String> {
+        let input = match fs::read_to_string(path) {
+            Ok(input) => input,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        };
+        History::parse(&input).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Adds `entries` to the end of the file at `path`, creating it if needed.
+    pub fn append(path: &Path, entries: &[Entry]) -> Result<(), String> {
+        let error = |e: std::io::Error| format!("{}: {}", path.display(), e);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(error)?;
+        for entry in entries {
+            writeln!(file, "{}", entry).map_err(error)?;
+        }
+        Ok(())
+    }
+
+    /// All samples of `phase` of `day` measured at `commit`.
+    pub fn samples(&self, commit: &str, day: u32, phase: &str) -> Vec<Duration> {
+        self.entries
+            .iter()
+            .filter(|e| e.day == day && e.phase == phase && same_commit(&e.commit, commit))
+            .flat_map(|e| e.samples.iter().copied())
+            .collect()
+    }
+
+    /// Compares every phase of `days` at `commit` with `baseline`, phases
+    /// without samples on both sides are left out.
+    pub fn compare(&self, baseline: &str, commit: &str, days: &[u32]) -> Vec<Comparison> {
+        let mut ret = Vec::new();
+        for day in days {
+            for phase in PHASES {
+                let before = self.samples(baseline, *day, phase);
+                let after = self.samples(commit, *day, phase);
+                if !before.is_empty() && !after.is_empty() {
+                    ret.push(Comparison::new(*day, phase, &before, &after));
+                }
+            }
+        }
+        ret
+    }
+}
+
+/// How a phase changed between two commits.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Verdict {
+    Regression,
+    Improvement,
+    Unchanged,
+    TooFewSamples,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Verdict::Regression => "regression",
+            Verdict::Improvement => "improvement",
+            Verdict::Unchanged => "unchanged",
+            Verdict::TooFewSamples => "too few samples",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The medians of one phase at two commits and whether they differ
+/// significantly.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Comparison {
+    pub day: u32,
+    pub phase: &'static str,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change of the median, `0.1` is 10% slower.
+    pub change: f64,
+    /// Mann-Whitney U statistic of the current samples as a standard score,
+    /// positive when they tend to be slower.
+    pub z: f64,
+    pub verdict: Verdict,
+}
+
+impl Comparison {
+    /// Uses a rank test, the run times of a solver are far from normally
+    /// distributed and a few slow outliers must not decide.
+    pub fn new(
+        day: u32,
+        phase: &'static str,
+        before: &[Duration],
+        after: &[Duration],
+    ) -> Comparison {
+        let baseline = Stats::new(before).median;
+        let current = Stats::new(after).median;
+        let change = match baseline.is_zero() {
+            true => 0.0,
+            false => current.as_secs_f64() / baseline.as_secs_f64() - 1.0,
+        };
+        let z = rank_sum_z(before, after);
+        let verdict = match z {
+            _ if before.len() < MIN_SAMPLES || after.len() < MIN_SAMPLES => Verdict::TooFewSamples,
+            z if z > Z_CRITICAL && change >= MIN_CHANGE => Verdict::Regression,
+            z if z < -Z_CRITICAL && change <= -MIN_CHANGE => Verdict::Improvement,
+            _ => Verdict::Unchanged,
+        };
+        Comparison {
+            day,
+            phase,
+            baseline,
+            current,
+            change,
+            z,
+            verdict,
+        }
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>3}  {:<6}{:>12}{:>12}{:>9}{:>7}  {}",
+            self.day,
+            self.phase,
+            format!("{:.2?}", self.baseline),
+            format!("{:.2?}", self.current),
+            format!("{:+.1}%", self.change * 100.0),
+            format!("{:.1}", self.z),
+            self.verdict
+        )
+    }
+}
+
+/// Header of the rows written by `Comparison`.
+pub const COMPARISON_HEADER: &str = "Day  Phase     Baseline     Current   Change      z  Verdict";
+
+/// The rank sum of `after` among all samples as a standard score, with the
+/// normal approximation of its distribution. Ties get their average rank.
+fn rank_sum_z(before: &[Duration], after: &[Duration]) -> f64 {
+    let (n1, n2) = (before.len() as f64, after.len() as f64);
+    let mut all = before
+        .iter()
+        .map(|d| (*d, false))
+        .chain(after.iter().map(|d| (*d, true)))
+        .collect::<Vec<(Duration, bool)>>();
+    all.sort();
+    let mut rank_sum = 0.0;
+    let mut idx = 0;
+    while idx < all.len() {
+        let end = idx
+            + all[idx..]
+                .iter()
+                .take_while(|(d, _)| *d == all[idx].0)
+                .count();
+        // ranks idx + 1 ..= end shared by all the tied samples
+        let rank = (idx + 1 + end) as f64 / 2.0;
+        rank_sum += rank
+            * all[idx..end]
+                .iter()
+                .filter(|(_, is_after)| *is_after)
+                .count() as f64;
+        idx = end;
+    }
+    let u = rank_sum - n2 * (n2 + 1.0) / 2.0;
+    let sd = (n1 * n2 * (n1 + n2 + 1.0) / 12.0).sqrt();
+    match sd > 0.0 {
+        true => (u - n1 * n2 / 2.0) / sd,
+        false => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ms(samples: &[u64]) -> Vec<Duration> {
+        samples
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect()
+    }
+
+    #[test]
+    fn test_history() {
+        let history = History::parse(
+            "# COMMIT DAY PHASE NS...\nabc123 20 part1 10 12\nabc123 20 part1 11\nabc123-dirty 20 part1 50",
+        )
+        .expect("Invalid history");
+        let samples = history.samples("abc", 20, "part1");
+        assert!(samples == [10, 12, 11].map(Duration::from_nanos));
+        assert!(history.samples("abc-dirty", 20, "part1") == [Duration::from_nanos(50)]);
+        assert!(history.samples("abd", 20, "part1").is_empty());
+        assert!(history.samples("", 20, "part1").is_empty());
+
+        let parsed = History::parse(&history.entries[0].to_string()).expect("Invalid entry");
+        assert!(parsed.entries[..] == history.entries[..1]);
+        let err = History::parse("abc 20 part3 10").err();
+        assert!(err == Some(ParseError::new(0, 7, "parse, part1 or part2")));
+        assert!(History::parse("abc 20").is_err());
+        assert!(History::parse("abc 20 part1 1ms").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let before = ms(&[10, 11, 10, 12, 11, 10, 11]);
+        let slower = ms(&[13, 14, 13, 15, 13, 14, 13]);
+        let noisy = ms(&[9, 12, 10, 11, 12, 10, 11]);
+        let comparison = Comparison::new(23, "part2", &before, &slower);
+        assert!(comparison.verdict == Verdict::Regression);
+        assert!((comparison.change - 2.0 / 11.0).abs() < 1e-9);
+        assert!(Comparison::new(23, "part2", &slower, &before).verdict == Verdict::Improvement);
+        assert!(Comparison::new(23, "part2", &before, &noisy).verdict == Verdict::Unchanged);
+        assert!(
+            Comparison::new(23, "part2", &before, &slower[..3]).verdict == Verdict::TooFewSamples
+        );
+        assert!(rank_sum_z(&before, &before) == 0.0);
+
+        let history = History {
+            entries: vec![
+                Entry {
+                    commit: "aaa".to_string(),
+                    day: 23,
+                    phase: "part2",
+                    samples: before,
+                },
+                Entry {
+                    commit: "bbb".to_string(),
+                    day: 23,
+                    phase: "part2",
+                    samples: slower,
+                },
+            ],
+        };
+        let comparisons = history.compare("aaa", "bbb", &[20, 23]);
+        assert!(comparisons.len() == 1 && comparisons[0].verdict == Verdict::Regression);
+        assert!(comparisons[0]
+            .to_string()
+            .starts_with(" 23  part2      11.00ms     13.00ms   +18.2%"));
+    }
+}
@@ -0,0 +1,194 @@
+use std::fs;
+use std::io::{self, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::render::{Frame, Render};
+use crate::utils::Grid;
+
+/// 8 bit red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// Pixel type of one of the binary netpbm formats.
+pub trait Pixel: Copy {
+    /// Magic number at the start of the file.
+    const MAGIC: &'static str;
+    const EXTENSION: &'static str;
+
+    fn push_to(&self, out: &mut Vec<u8>);
+}
+
+/// Grey level, written as PGM.
+impl Pixel for u8 {
+    const MAGIC: &'static str = "P5";
+    const EXTENSION: &'static str = "pgm";
+
+    fn push_to(&self, out: &mut Vec<u8>) {
+        out.push(*self);
+    }
+}
+
+/// Colour, written as PPM.
+impl Pixel for Rgb {
+    const MAGIC: &'static str = "P6";
+    const EXTENSION: &'static str = "ppm";
+
+    fn push_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self);
+    }
+}
+
+/// Picture stored row by row, either grey (`Image<u8>`) or colour (`Image<Rgb>`).
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Image<P: Pixel> {
+    width: usize,
+    height: usize,
+    pixels: Vec<P>,
+}
+
+impl<P: Pixel> Image<P> {
+    /// One pixel per cell of `grid`, coloured by `pixel`.
+    pub fn from_grid<T, F: FnMut(&T) -> P>(grid: &Grid<T>, mut pixel: F) -> Image<P> {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.rows().flatten().map(&mut pixel).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Every pixel becomes a `factor` by `factor` square, small grids are hard to see otherwise.
+    pub fn scaled(&self, factor: usize) -> Image<P> {
+        let pixels = self
+            .pixels
+            .chunks(self.width.max(1))
+            .flat_map(|row| {
+                let row = row
+                    .iter()
+                    .flat_map(|p| std::iter::repeat_n(*p, factor))
+                    .collect::<Vec<P>>();
+                std::iter::repeat_n(row, factor).flatten()
+            })
+            .collect();
+        Image {
+            width: self.width * factor,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    /// The complete file contents.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut ret = format!("{}\n{} {}\n255\n", P::MAGIC, self.width, self.height).into_bytes();
+        for p in self.pixels.iter() {
+            p.push_to(&mut ret);
+        }
+        ret
+    }
+
+    pub fn write<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        out.write_all(&self.to_bytes())
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_bytes())
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+    }
+}
+
+impl Image<Rgb> {
+    /// One pixel per cell in the colour of the cell, glyphs are lost.
+    pub fn from_frame(frame: &Frame) -> Image<Rgb> {
+        Image::from_grid(frame.cells(), |cell| cell.colour.rgb())
+    }
+}
+
+/// Path of image number `idx` of a sequence.
+pub fn sequence_path(dir: &Path, prefix: &str, idx: usize, extension: &str) -> PathBuf {
+    dir.join(format!("{}_{:05}.{}", prefix, idx, extension))
+}
+
+/// Saves the frames `range` of `sim` to `dir` as numbered PPM images, frame 0 is
+/// the state before the first `advance`. Stops early when the simulation is over
+/// and returns the written paths.
+pub fn save_frames(
+    sim: &mut dyn Render,
+    range: Range<usize>,
+    scale: usize,
+    dir: &Path,
+    prefix: &str,
+) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut ret = Vec::new();
+    for idx in 0..range.end {
+        if idx >= range.start {
+            let path = sequence_path(dir, prefix, idx, Rgb::EXTENSION);
+            Image::from_frame(&sim.frame()).scaled(scale).save(&path)?;
+            ret.push(path);
+        }
+        if idx + 1 < range.end && !sim.advance() {
+            break;
+        }
+    }
+    Ok(ret)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::render::{Cell, Colour};
+
+    #[test]
+    fn test_pgm() {
+        let grid = Grid::parse_chars("#.\n.#").expect("Invalid grid");
+        let image = Image::from_grid(&grid, |c| if *c == '#' { 255 } else { 0 });
+        assert!(image.to_bytes() == b"P5\n2 2\n255\n\xff\x00\x00\xff");
+
+        let scaled = image.scaled(2);
+        assert!(scaled.width() == 4 && scaled.height() == 4);
+        assert!(scaled
+            .to_bytes()
+            .ends_with(&[255, 255, 0, 0, 0, 0, 255, 255, 0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut frame = Frame::blank(3, 1, Cell::plain('.'));
+        frame.set(
+            &crate::utils::PointT { x: 1, y: 0 },
+            Cell::new('o', Colour::Red),
+        );
+        let image = Image::from_frame(&frame);
+        let mut out = Vec::new();
+        image.write(&mut out).expect("Write failed");
+        let red = Colour::Red.rgb();
+        assert!(out[..11] == *b"P6\n3 1\n255\n");
+        assert!(out[11..] == [[0, 0, 0], red, [0, 0, 0]].concat());
+    }
+
+    #[test]
+    fn test_save_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc24rust-frames-{}", std::process::id()));
+        let input = "p=0,0 v=1,0\np=2,3 v=-1,1";
+        let mut sim = crate::render::animation(14).expect("Day 14 has an animation")(
+            input,
+            &crate::params::Params::new(),
+        )
+        .expect("Invalid input");
+        let paths = save_frames(sim.as_mut(), 2..4, 1, &dir, "day14").expect("Save failed");
+        assert!(paths == [2, 3].map(|idx| sequence_path(&dir, "day14", idx, "ppm")));
+        let image = fs::read(&paths[0]).expect("Image missing");
+        assert!(image.starts_with(b"P6\n101 103\n255\n"));
+        // the first robot moved two tiles to the right
+        let green = Colour::Green.rgb();
+        assert!(image[15 + 2 * 3..15 + 3 * 3] == green);
+        fs::remove_dir_all(&dir).expect("Cleanup failed");
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::parse::{parse_in_line, ParseError};
+
+/// Where the puzzle input of a day is read from.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum InputSource {
+    /// `src/dayNN/input.txt` inside the crate directory.
+    Default(u32),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Picks the source from a command line argument, `-` is stdin.
+    pub fn from_arg(day: u32, arg: Option<&str>) -> InputSource {
+        match arg {
+            None => InputSource::Default(day),
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        let path = match self {
+            InputSource::Default(day) => default_input_path(*day),
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => {
+                let mut ret = String::new();
+                io::stdin().read_to_string(&mut ret)?;
+                return Ok(ret);
+            }
+        };
+        fs::read_to_string(&path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+    }
+}
+
+/// Resolves against the crate root, so it does not depend on the working directory.
+pub fn default_input_path(day: u32) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("src/day{:02}/input.txt", day))
+}
+
+/// Puzzle input with `\r\n` line endings turned into `\n`, trailing whitespace
+/// removed from every line and without trailing blank lines. Lines keep their
+/// index, so a `ParseError` still points into the original file.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Input {
+    text: String,
+}
+
+/// A block of lines separated from the others by blank lines.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Section<'a> {
+    /// Index of the first line in the whole input.
+    pub line_idx: usize,
+    pub text: &'a str,
+}
+
+impl Input {
+    pub fn new(raw: &str) -> Input {
+        let mut text = raw
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<&str>>()
+            .join("\n");
+        text.truncate(text.trim_end_matches('\n').len());
+        Input { text }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.text.lines()
+    }
+
+    /// The blank line separated blocks, several blank lines in a row count as one.
+    pub fn sections(&self) -> Vec<Section<'_>> {
+        let mut ret = Vec::new();
+        let mut start: Option<(usize, usize)> = None;
+        let mut offset = 0;
+        for (y, line) in self.text.split('\n').enumerate() {
+            match (line.is_empty(), start) {
+                (false, None) => start = Some((y, offset)),
+                (true, Some((line_idx, from))) => {
+                    ret.push(Section {
+                        line_idx,
+                        text: &self.text[from..offset - 1],
+                    });
+                    start = None;
+                }
+                _ => {}
+            }
+            offset += line.len() + 1;
+        }
+        if let Some((line_idx, from)) = start {
+            ret.push(Section {
+                line_idx,
+                text: &self.text[from..],
+            });
+        }
+        ret
+    }
+}
+
+impl<'a> Section<'a> {
+    /// The lines with their index in the whole input.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        let line_idx = self.line_idx;
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(i, line)| (line_idx + i, line))
+    }
+
+    /// Parses every line as `key: value`, `expected` describes the values.
+    pub fn key_values<T: FromStr>(
+        &self,
+        expected: &str,
+    ) -> Result<HashMap<&'a str, T>, ParseError> {
+        let mut ret = HashMap::new();
+        for (y, line) in self.numbered_lines() {
+            let (key, value) = parse_key_value(y, line, expected)?;
+            if ret.insert(key, value).is_some() {
+                return Err(ParseError::new(y, 0, "a unique key"));
+            }
+        }
+        Ok(ret)
+    }
+}
+
+/// Splits a `key: value` line, the space after the colon is optional.
+pub fn split_key_value(line_idx: usize, line: &str) -> Result<(&str, &str), ParseError> {
+    match line.split_once(':') {
+        Some((key, value)) if !key.is_empty() => Ok((key, value.trim_start())),
+        _ => Err(ParseError::new(line_idx, 0, "KEY: VALUE")),
+    }
+}
+
+/// Splits a `key: value` line and parses the value, `expected` describes the value.
+pub fn parse_key_value<'a, T: FromStr>(
+    line_idx: usize,
+    line: &'a str,
+    expected: &str,
+) -> Result<(&'a str, T), ParseError> {
+    let (key, value) = split_key_value(line_idx, line)?;
+    Ok((key, parse_in_line(line_idx, line, value, expected)?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_from_arg() {
+        assert!(InputSource::from_arg(3, None) == InputSource::Default(3));
+        assert!(InputSource::from_arg(3, Some("-")) == InputSource::Stdin);
+        assert!(
+            InputSource::from_arg(3, Some("example.txt"))
+                == InputSource::File(PathBuf::from("example.txt"))
+        );
+    }
+
+    #[test]
+    fn test_default_path() {
+        let input = InputSource::Default(1).read().unwrap();
+        assert!(!input.is_empty());
+        assert!(default_input_path(25).ends_with("src/day25/input.txt"));
+    }
+
+    #[test]
+    fn test_normalise() {
+        let input = Input::new("ab \r\n\r\ncd\t\r\n\r\n\n");
+        assert!(input.as_str() == "ab\n\ncd");
+        assert!(input.lines().collect::<Vec<&str>>() == ["ab", "", "cd"]);
+        assert!(Input::new("\n\nx").lines().count() == 3);
+        assert!(Input::new(" \r\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = Input::new("\na\nb\n\n \n\nc\r\n\r\nd\ne\n");
+        let sections = input.sections();
+        assert!(sections.iter().map(|s| s.text).collect::<Vec<&str>>() == ["a\nb", "c", "d\ne"]);
+        assert!(sections.iter().map(|s| s.line_idx).collect::<Vec<usize>>() == [1, 6, 8]);
+        assert!(sections[2].numbered_lines().collect::<Vec<_>>() == [(8, "d"), (9, "e")]);
+        assert!(Input::new("").sections().is_empty());
+    }
+
+    #[test]
+    fn test_key_values() {
+        let input = Input::new("Register A: 729\nRegister B:0\n\nx00: 1\nx00: 0\n");
+        let sections = input.sections();
+        let registers = sections[0]
+            .key_values::<i64>("a register value")
+            .expect("Invalid");
+        assert!(registers.get("Register A") == Some(&729));
+        assert!(registers.get("Register B") == Some(&0));
+        let err = sections[1].key_values::<u8>("a level").err();
+        assert!(err == Some(ParseError::new(4, 0, "a unique key")));
+
+        assert!(split_key_value(0, "Program: 0,3") == Ok(("Program", "0,3")));
+        assert!(split_key_value(2, "no colon").err() == Some(ParseError::new(2, 0, "KEY: VALUE")));
+        let err = parse_key_value::<i64>(1, "a: x", "a number").err();
+        assert!(err == Some(ParseError::new(1, 3, "a number")));
+    }
+}
@@ -1,7 +1,58 @@
+#![allow(clippy::needless_return)]
+
+pub mod bench;
+pub mod cache;
+pub mod cancel;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod fixtures;
+pub mod generate;
+pub mod history;
+pub mod image;
+pub mod input;
+pub mod params;
+pub mod parse;
+pub mod render;
+pub mod report;
+pub mod solution;
+pub mod trace;
+pub mod verify;
+
 pub mod utils {
-    use num::{Integer, Signed};
+    pub mod direction;
+    pub mod grid;
+    pub mod search;
+
+    pub use direction::Direction;
+    pub use grid::Grid;
+
+    use num::traits::Euclid;
+    use num::{NumCast, PrimInt, Signed, ToPrimitive};
     use std::{cmp::min, fmt::Display, ops};
-    #[derive(PartialEq, Eq, Hash, Clone)]
+    #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
     pub struct PointT<T: Ord> {
         pub x: T,
         pub y: T,
@@ -49,6 +100,87 @@ pub mod utils {
         }
     }
 
+    impl<T> ops::Mul<PointT<T>> for PointT<T>
+    where
+        T: Ord + Copy + ops::Mul<Output = T>,
+    {
+        type Output = PointT<T>;
+
+        /// Component wise product.
+        fn mul(self, _rhs: PointT<T>) -> PointT<T> {
+            return PointT {
+                x: self.x * _rhs.x,
+                y: self.y * _rhs.y,
+            };
+        }
+    }
+
+    impl<T> ops::Neg for PointT<T>
+    where
+        T: Ord + Copy + ops::Neg<Output = T>,
+    {
+        type Output = PointT<T>;
+
+        fn neg(self) -> PointT<T> {
+            return PointT {
+                x: -self.x,
+                y: -self.y,
+            };
+        }
+    }
+
+    impl<T> ops::AddAssign for PointT<T>
+    where
+        T: Ord + Copy + ops::AddAssign,
+    {
+        fn add_assign(&mut self, _rhs: PointT<T>) {
+            self.x += _rhs.x;
+            self.y += _rhs.y;
+        }
+    }
+
+    impl<T> ops::SubAssign for PointT<T>
+    where
+        T: Ord + Copy + ops::SubAssign,
+    {
+        fn sub_assign(&mut self, _rhs: PointT<T>) {
+            self.x -= _rhs.x;
+            self.y -= _rhs.y;
+        }
+    }
+
+    impl<T> ops::MulAssign<T> for PointT<T>
+    where
+        T: Ord + Copy + ops::MulAssign,
+    {
+        fn mul_assign(&mut self, _rhs: T) {
+            self.x *= _rhs;
+            self.y *= _rhs;
+        }
+    }
+
+    impl From<PointT<i32>> for PointT<i64> {
+        fn from(p: PointT<i32>) -> PointT<i64> {
+            return PointT {
+                x: p.x.into(),
+                y: p.y.into(),
+            };
+        }
+    }
+
+    impl<T> PointT<T>
+    where
+        T: Ord + Copy + ToPrimitive,
+    {
+        /// Converts the coordinates to `U`, `None` if one of them does not fit.
+        pub fn try_cast<U: Ord + NumCast>(&self) -> Option<PointT<U>> {
+            return Some(PointT {
+                x: U::from(self.x)?,
+                y: U::from(self.y)?,
+            });
+        }
+    }
+
     impl<T> Ord for PointT<T>
     where
         T: Ord + Copy,
@@ -63,11 +195,7 @@ pub mod utils {
         T: Ord + Copy,
     {
         fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-            match self.x.partial_cmp(&other.x) {
-                Some(core::cmp::Ordering::Equal) => {}
-                ord => return ord,
-            }
-            self.y.partial_cmp(&other.y)
+            Some(self.cmp(other))
         }
     }
 
@@ -116,13 +244,75 @@ pub mod utils {
         }
     }
 
+    impl<T> PointT<T>
+    where
+        T: PrimInt + Signed,
+    {
+        fn offset(&self, dx: i64, dy: i64) -> PointT<T> {
+            let cast = |v: i64| T::from(v).expect("Offset out of range");
+            return PointT {
+                x: self.x + cast(dx),
+                y: self.y + cast(dy),
+            };
+        }
+
+        fn radius(r: T) -> i64 {
+            return r.to_i64().expect("Radius out of range").max(0);
+        }
+
+        /// Points with a Manhattan distance of exactly `r`, in the order of
+        /// `get_points_with_distance`. A radius of 0 yields the point itself.
+        pub fn manhattan_ring(&self, r: T) -> impl Iterator<Item = PointT<T>> {
+            let (c, r) = (*self, PointT::radius(r));
+            let center = (r == 0).then_some(c);
+            let ring = (0..r).flat_map(move |d| {
+                [
+                    c.offset(d, r - d),
+                    c.offset(r - d, -d),
+                    c.offset(-d, -(r - d)),
+                    c.offset(-(r - d), d),
+                ]
+            });
+            return center.into_iter().chain(ring);
+        }
+
+        /// Points with a Manhattan distance of at most `r`, ring by ring.
+        pub fn manhattan_ball(&self, r: T) -> impl Iterator<Item = PointT<T>> {
+            let c = *self;
+            return (0..=PointT::radius(r))
+                .flat_map(move |d| c.manhattan_ring(T::from(d).expect("Radius out of range")));
+        }
+
+        /// Border of the square of side `2 * r + 1` around the point.
+        pub fn chebyshev_ring(&self, r: T) -> impl Iterator<Item = PointT<T>> {
+            let (c, r) = (*self, PointT::radius(r));
+            let center = (r == 0).then_some(c);
+            let ring = (-r..r).flat_map(move |i| {
+                [
+                    c.offset(i, -r),
+                    c.offset(r, i),
+                    c.offset(-i, r),
+                    c.offset(-r, -i),
+                ]
+            });
+            return center.into_iter().chain(ring);
+        }
+
+        /// The full square of side `2 * r + 1` around the point, ring by ring.
+        pub fn chebyshev_ball(&self, r: T) -> impl Iterator<Item = PointT<T>> {
+            let c = *self;
+            return (0..=PointT::radius(r))
+                .flat_map(move |d| c.chebyshev_ring(T::from(d).expect("Radius out of range")));
+        }
+    }
+
     impl<T: Ord + Display> Display for PointT<T> {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             write!(f, "{}, {}", self.x, self.y)
         }
     }
 
-    #[derive(PartialEq, Eq, Hash, Clone)]
+    #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
     pub struct RectT<T: Ord> {
         pub x: T,
         pub y: T,
@@ -130,10 +320,58 @@ pub mod utils {
         pub height: T,
     }
 
+    /// Where the middle row or column of an odd sized rect goes when it is split in halves.
+    #[derive(PartialEq, Eq, Clone, Copy, Debug)]
+    pub enum CenterLine {
+        /// In neither half.
+        Exclude,
+        /// In the top or left half.
+        First,
+        /// In the bottom or right half.
+        Second,
+    }
+
     impl<T> RectT<T>
     where
-        T: Ord + Copy + ops::Add + ops::Div<T> + Signed + Integer,
+        T: PrimInt + Signed + Euclid,
     {
+        /// Rect spanning from `min` up to but excluding `max`, empty if `max` is not past `min`.
+        pub fn from_corners(min: PointT<T>, max: PointT<T>) -> RectT<T> {
+            return RectT {
+                x: min.x,
+                y: min.y,
+                width: (max.x - min.x).max(T::zero()),
+                height: (max.y - min.y).max(T::zero()),
+            };
+        }
+
+        /// Top left corner.
+        pub fn min(&self) -> PointT<T> {
+            return PointT {
+                x: self.x,
+                y: self.y,
+            };
+        }
+
+        /// One past the bottom right corner.
+        pub fn max(&self) -> PointT<T> {
+            return PointT {
+                x: self.x + self.width,
+                y: self.y + self.height,
+            };
+        }
+
+        pub fn is_empty(&self) -> bool {
+            return self.width <= T::zero() || self.height <= T::zero();
+        }
+
+        pub fn area(&self) -> T {
+            if self.is_empty() {
+                return T::zero();
+            }
+            return self.width * self.height;
+        }
+
         pub fn contains_point(&self, p: &PointT<T>) -> bool {
             return p.x >= self.x
                 && p.x < self.x + self.width
@@ -141,74 +379,220 @@ pub mod utils {
                 && p.y < self.y + self.height;
         }
 
-        pub fn get_quadrants(&self) -> [RectT<T>; 4] {
-            let two = T::one() + T::one();
-            let half_width = self.width / two;
-            let half_height = self.height / two;
-            [
-                RectT {
-                    x: self.x,
-                    y: self.y,
-                    width: half_width,
-                    height: half_height,
-                },
-                RectT {
-                    x: self.width - half_width,
-                    y: self.y,
-                    width: half_width,
-                    height: half_height,
-                },
-                RectT {
-                    x: self.x,
-                    y: self.height - half_height,
-                    width: half_width,
-                    height: half_height,
+        /// All contained points, row by row.
+        pub fn points(&self) -> impl Iterator<Item = PointT<T>> {
+            let (min, max) = (self.min(), self.max());
+            return num::range(min.y, max.y)
+                .flat_map(move |y| num::range(min.x, max.x).map(move |x| PointT { x, y }));
+        }
+
+        /// The overlapping part of both rects, `None` if they do not overlap.
+        pub fn intersection(&self, other: &RectT<T>) -> Option<RectT<T>> {
+            let min = PointT {
+                x: self.x.max(other.x),
+                y: self.y.max(other.y),
+            };
+            let (a, b) = (self.max(), other.max());
+            let max = PointT {
+                x: a.x.min(b.x),
+                y: a.y.min(b.y),
+            };
+            let ret = RectT::from_corners(min, max);
+            return (!ret.is_empty()).then_some(ret);
+        }
+
+        /// The smallest rect containing both rects.
+        pub fn union(&self, other: &RectT<T>) -> RectT<T> {
+            if other.is_empty() {
+                return *self;
+            }
+            if self.is_empty() {
+                return *other;
+            }
+            let (a, b) = (self.max(), other.max());
+            return RectT::from_corners(
+                PointT {
+                    x: self.x.min(other.x),
+                    y: self.y.min(other.y),
                 },
-                RectT {
-                    x: self.width - half_width,
-                    y: self.height - half_height,
-                    width: half_width,
-                    height: half_height,
+                PointT {
+                    x: a.x.max(b.x),
+                    y: a.y.max(b.y),
                 },
-            ]
+            );
+        }
+
+        /// Wraps `p` around the edges, so the result is always contained in a non empty rect.
+        pub fn wrap(&self, p: &PointT<T>) -> PointT<T> {
+            return PointT {
+                x: self.x + (p.x - self.x).rem_euclid(&self.width),
+                y: self.y + (p.y - self.y).rem_euclid(&self.height),
+            };
+        }
+
+        /// Lengths of the first and second half of `len` and the offset of the second one.
+        fn halves(len: T, center: CenterLine) -> (T, T, T) {
+            let two = T::one() + T::one();
+            let half = len / two;
+            let odd = len % two;
+            return match center {
+                CenterLine::Exclude => (half, half, half + odd),
+                CenterLine::First => (half + odd, half, half + odd),
+                CenterLine::Second => (half, half + odd, half),
+            };
+        }
+
+        /// Top left, top right, bottom left and bottom right quadrant.
+        pub fn get_quadrants(&self, center: CenterLine) -> [RectT<T>; 4] {
+            let (left, right, right_x) = RectT::halves(self.width, center);
+            let (top, bottom, bottom_y) = RectT::halves(self.height, center);
+            let quadrant = |x: T, y: T, width: T, height: T| RectT {
+                x: self.x + x,
+                y: self.y + y,
+                width,
+                height,
+            };
+            return [
+                quadrant(T::zero(), T::zero(), left, top),
+                quadrant(right_x, T::zero(), right, top),
+                quadrant(T::zero(), bottom_y, left, bottom),
+                quadrant(right_x, bottom_y, right, bottom),
+            ];
         }
     }
 
-    pub struct RingBuffer<T: Sized, const COUNT: usize> {
-        buffer: [T; COUNT],
+    /// Keeps the last `COUNT` pushed items, index 0 is the oldest one.
+    #[derive(Clone, Debug)]
+    pub struct RingBuffer<T, const COUNT: usize> {
+        buffer: [Option<T>; COUNT],
         inserted: usize,
     }
 
-    impl<T: Sized + Copy + Default, const COUNT: usize> RingBuffer<T, COUNT> {
+    impl<T, const COUNT: usize> RingBuffer<T, COUNT> {
         pub fn new() -> RingBuffer<T, COUNT> {
             RingBuffer {
-                buffer: [T::default(); COUNT],
+                buffer: std::array::from_fn(|_| None),
                 inserted: 0,
             }
         }
 
+        /// Appends `item`, the oldest item is dropped once the buffer is full.
         pub fn push(&mut self, item: T) {
             let idx = self.inserted.rem_euclid(COUNT);
-            self.buffer[idx] = item;
+            self.buffer[idx] = Some(item);
             self.inserted += 1;
         }
 
-        pub fn get(&self) -> impl Iterator<Item = T> {
-            let idx: usize;
-            let len: usize;
+        fn slot(&self, idx: usize) -> usize {
             if self.inserted < COUNT {
-                idx = 0;
-                len = self.inserted;
-            } else {
-                idx = self.inserted.rem_euclid(COUNT);
-                len = COUNT;
+                return idx;
             }
-            return self.buffer.into_iter().cycle().skip(idx).take(len);
+            return (self.inserted + idx).rem_euclid(COUNT);
+        }
+
+        /// The items from oldest to newest.
+        pub fn iter(&self) -> impl Iterator<Item = &T> {
+            return (0..self.len()).map(|i| &self[i]);
+        }
+
+        /// Oldest item.
+        pub fn front(&self) -> Option<&T> {
+            return (!self.is_empty()).then(|| &self[0]);
+        }
+
+        /// Most recently pushed item.
+        pub fn back(&self) -> Option<&T> {
+            return (!self.is_empty()).then(|| &self[self.len() - 1]);
         }
 
         pub fn len(&self) -> usize {
             return min(self.inserted, COUNT);
         }
+
+        pub fn is_empty(&self) -> bool {
+            return self.inserted == 0;
+        }
+
+        pub fn is_full(&self) -> bool {
+            return self.inserted >= COUNT;
+        }
+    }
+
+    impl<T: Clone, const COUNT: usize> RingBuffer<T, COUNT> {
+        pub fn get(&self) -> impl Iterator<Item = T> + '_ {
+            return self.iter().cloned();
+        }
+
+        /// The items from oldest to newest, `None` until the buffer is full.
+        pub fn to_array(&self) -> Option<[T; COUNT]> {
+            if !self.is_full() {
+                return None;
+            }
+            return Some(std::array::from_fn(|i| self[i].clone()));
+        }
+    }
+
+    impl<T: Copy + Into<i64>, const COUNT: usize> RingBuffer<T, COUNT> {
+        /// Packs `items` into the low `bits` of one `u64` each, the last item ends
+        /// up in the least significant bits. Items must fit into `bits` as two's
+        /// complement, and there must not be more than `COUNT` of them.
+        pub fn pack(items: &[T], bits: u32) -> u64 {
+            assert!(items.len() <= COUNT, "More items than the window holds");
+            return RingBuffer::<T, COUNT>::pack_iter(items.iter(), bits);
+        }
+
+        fn pack_iter<'a>(items: impl Iterator<Item = &'a T>, bits: u32) -> u64
+        where
+            T: 'a,
+        {
+            assert!(bits > 0 && bits as usize * COUNT <= 64, "Key does not fit");
+            let mask = u64::MAX >> (64 - bits);
+            return items.fold(0, |acc, item| {
+                let item: i64 = (*item).into();
+                (acc << bits) | (item as u64 & mask)
+            });
+        }
+
+        /// Inverse of `pack` for a full window.
+        pub fn unpack(key: u64, bits: u32) -> [i64; COUNT] {
+            let mut ret = [0; COUNT];
+            for (i, item) in ret.iter_mut().enumerate() {
+                let shift = (COUNT - 1 - i) as u32 * bits;
+                // move the item to the top and back down to sign extend it
+                *item = ((key >> shift) << (64 - bits)) as i64 >> (64 - bits);
+            }
+            return ret;
+        }
+
+        /// The current window as a small hashable key, see `pack`.
+        pub fn packed_key(&self, bits: u32) -> u64 {
+            return RingBuffer::<T, COUNT>::pack_iter(self.iter(), bits);
+        }
+    }
+
+    impl<T, const COUNT: usize> ops::Index<usize> for RingBuffer<T, COUNT> {
+        type Output = T;
+
+        fn index(&self, idx: usize) -> &T {
+            assert!(idx < self.len(), "Index {} out of range", idx);
+            return self.buffer[self.slot(idx)]
+                .as_ref()
+                .expect("Slot is filled");
+        }
+    }
+
+    impl<T, const COUNT: usize> ops::IndexMut<usize> for RingBuffer<T, COUNT> {
+        fn index_mut(&mut self, idx: usize) -> &mut T {
+            assert!(idx < self.len(), "Index {} out of range", idx);
+            let slot = self.slot(idx);
+            return self.buffer[slot].as_mut().expect("Slot is filled");
+        }
+    }
+
+    impl<T, const COUNT: usize> Default for RingBuffer<T, COUNT> {
+        fn default() -> Self {
+            Self::new()
+        }
     }
 }
 
@@ -216,7 +600,7 @@ pub mod utils {
 mod test {
     use std::collections::HashSet;
 
-    use utils::{PointT, RingBuffer};
+    use utils::{CenterLine, PointT, RectT, RingBuffer};
 
     use super::*;
     #[test]
@@ -247,6 +631,129 @@ mod test {
     }
 
     #[test]
+    fn test_point_ops() {
+        let mut p = PointT { x: 2, y: -3 };
+        p += PointT { x: 1, y: 1 };
+        assert!(p == PointT { x: 3, y: -2 });
+        p -= PointT { x: 3, y: 0 };
+        assert!(p == PointT { x: 0, y: -2 });
+        p *= 4;
+        assert!(-p == PointT { x: 0, y: 8 });
+        assert!(PointT { x: 2, y: 3 } * PointT { x: -1, y: 5 } == PointT { x: -2, y: 15 });
+
+        let wide: PointT<i64> = PointT { x: 7_i32, y: -1 }.into();
+        assert!(wide == PointT { x: 7, y: -1 });
+        assert!(wide.try_cast::<i32>() == Some(PointT { x: 7, y: -1 }));
+        assert!(wide.try_cast::<u8>().is_none());
+        assert!(PointT { x: i64::MAX, y: 0 }.try_cast::<i32>().is_none());
+    }
+
+    #[test]
+    fn test_rings() {
+        let point = PointT { x: 2_i64, y: 2 };
+        assert!(point.manhattan_ring(0).collect::<Vec<_>>() == [point]);
+        for r in 1..5 {
+            assert!(
+                point.manhattan_ring(r).collect::<Vec<_>>() == point.get_points_with_distance(r)
+            );
+            let chebyshev = point.chebyshev_ring(r).collect::<HashSet<_>>();
+            assert!(chebyshev.len() == 8 * r as usize);
+            assert!(chebyshev
+                .iter()
+                .all(|p| (p.x - point.x).abs().max((p.y - point.y).abs()) == r));
+        }
+        let ball = point.manhattan_ball(3).collect::<HashSet<_>>();
+        assert!(ball.len() == 25);
+        assert!(ball.iter().all(|p| p.distance(&point) <= 3));
+        assert!(point.chebyshev_ball(2).collect::<HashSet<_>>().len() == 25);
+    }
+
+    #[test]
+    fn test_rect() {
+        let rect = RectT {
+            x: -2_i64,
+            y: 3,
+            width: 3,
+            height: 2,
+        };
+        let points = rect.points().collect::<Vec<_>>();
+        assert!(points.len() as i64 == rect.area());
+        assert!(points[0] == PointT { x: -2, y: 3 } && points[5] == PointT { x: 0, y: 4 });
+        assert!(points.iter().all(|p| rect.contains_point(p)));
+        assert!(rect.wrap(&PointT { x: 1, y: 3 }) == PointT { x: -2, y: 3 });
+        assert!(rect.wrap(&PointT { x: -3, y: 2 }) == PointT { x: 0, y: 4 });
+
+        let other = RectT::from_corners(PointT { x: 0, y: 0 }, PointT { x: 4, y: 4 });
+        assert!(
+            rect.intersection(&other)
+                == Some(RectT::from_corners(
+                    PointT { x: 0, y: 3 },
+                    PointT { x: 1, y: 4 }
+                ))
+        );
+        assert!(
+            rect.union(&other)
+                == RectT::from_corners(PointT { x: -2, y: 0 }, PointT { x: 4, y: 5 })
+        );
+        let apart = RectT::from_corners(PointT { x: 5, y: 5 }, PointT { x: 6, y: 6 });
+        assert!(other.intersection(&apart).is_none());
+        assert!(RectT::from_corners(PointT { x: 1, y: 1 }, PointT { x: 0, y: 0 }).is_empty());
+    }
+
+    #[test]
+    fn test_quadrants() {
+        let rect = RectT {
+            x: 10_i64,
+            y: 20,
+            width: 5,
+            height: 4,
+        };
+        let q = rect.get_quadrants(CenterLine::Exclude);
+        assert!(
+            q[0] == RectT {
+                x: 10,
+                y: 20,
+                width: 2,
+                height: 2
+            }
+        );
+        assert!(
+            q[3] == RectT {
+                x: 13,
+                y: 22,
+                width: 2,
+                height: 2
+            }
+        );
+        let q = rect.get_quadrants(CenterLine::First);
+        assert!(
+            q[0].width == 3
+                && q[1]
+                    == RectT {
+                        x: 13,
+                        y: 20,
+                        width: 2,
+                        height: 2
+                    }
+        );
+        let q = rect.get_quadrants(CenterLine::Second);
+        assert!(
+            q[1] == RectT {
+                x: 12,
+                y: 20,
+                width: 3,
+                height: 2
+            }
+        );
+        for center in [CenterLine::First, CenterLine::Second] {
+            let q = rect.get_quadrants(center);
+            assert!(q.iter().map(|q| q.area()).sum::<i64>() == rect.area());
+            assert!(q.iter().fold(q[0], |acc, q| acc.union(q)) == rect);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::len_zero, clippy::useless_vec)]
     fn test_ring_buffer() {
         let mut rb = RingBuffer::<i32, 4>::new();
         let test_values = vec![1, 2, 3, 4, 5, 6, 7];
@@ -278,5 +785,46 @@ mod test {
         let items = rb.get().collect::<Vec<i32>>();
         assert!(items[..] == test_values[1..5]);
         assert!(rb.len() == 4);
-    } //
+    }
+
+    #[test]
+    fn test_ring_buffer_access() {
+        let mut rb = RingBuffer::<String, 3>::new();
+        assert!(rb.front().is_none() && rb.back().is_none());
+        for word in ["a", "b", "c", "d"] {
+            rb.push(word.to_string());
+        }
+        assert!(rb.front().map(String::as_str) == Some("b"));
+        assert!(rb.back().map(String::as_str) == Some("d"));
+        assert!(rb[1] == "c");
+        rb[1].push('!');
+        assert!(rb.iter().map(String::as_str).collect::<Vec<_>>() == ["b", "c!", "d"]);
+        assert!(rb.to_array() == Some(["b", "c!", "d"].map(String::from)));
+        assert!(RingBuffer::<String, 3>::new().to_array().is_none());
+    }
+
+    #[test]
+    fn test_ring_buffer_packed_key() {
+        let mut rb = RingBuffer::<i8, 4>::new();
+        let test_values = [1, 2, 3, 4, 5, 6, 7];
+        let concat_numbers = |items: &[i8]| items.iter().fold(0, |acc, i| acc * 256 + *i as u64);
+        assert!(rb.packed_key(8) == 0);
+        for i in 0..5 {
+            rb.push(test_values[i]);
+            let first = (i + 1).saturating_sub(4);
+            assert!(rb.packed_key(8) == concat_numbers(&test_values[first..=i]));
+        }
+
+        for items in [[-9, 9, 0, -1], [-128, 127, 1, -2]] {
+            let key = RingBuffer::<i8, 4>::pack(&items, 8);
+            assert!(RingBuffer::<i8, 4>::unpack(key, 8) == items.map(i64::from));
+        }
+        assert!(RingBuffer::<i8, 4>::pack(&[-1, 1], 5) == 0b11111_00001);
+    }
+
+    #[test]
+    #[should_panic(expected = "More items than the window holds")]
+    fn test_ring_buffer_pack_too_many() {
+        RingBuffer::<i8, 4>::pack(&[1, 2, 3, 4, 5], 8);
+    }
 }
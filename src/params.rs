@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+use crate::parse::{parse_in_line, ParseError};
+use crate::solution::PARAMS;
+
+/// A named knob of a day, like the size of the floor or the number of rounds.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    /// The value of the puzzle as given, the answers in `answers.txt` use it.
+    pub default: i64,
+    /// Smaller values are rejected.
+    pub min: i64,
+    /// Larger values are rejected, they would overflow or take forever.
+    pub max: i64,
+    pub help: &'static str,
+}
+
+/// The overridden parameters of one day, all others keep their default.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Params {
+    values: HashMap<String, i64>,
+}
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The overrides sorted by name.
+    pub fn entries(&self) -> Vec<(&str, i64)> {
+        let mut ret = self
+            .values
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+            .collect::<Vec<(&str, i64)>>();
+        ret.sort();
+        ret
+    }
+
+    /// The value of `param`, its default unless it was overridden.
+    pub fn get(&self, param: &Param) -> i64 {
+        self.values
+            .get(param.name)
+            .copied()
+            .unwrap_or(param.default)
+    }
+
+    /// Checks that every override names one of `declared` and is in its range.
+    pub fn check(&self, declared: &[Param]) -> Result<(), String> {
+        for (name, _) in self.entries() {
+            let Some(param) = declared.iter().find(|p| p.name == name) else {
+                return Err(format!("Unknown parameter '{}'", name));
+            };
+            if self.get(param) < param.min {
+                return Err(format!("{} has to be at least {}", name, param.min));
+            }
+            if self.get(param) > param.max {
+                return Err(format!("{} has to be at most {}", name, param.max));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a `NAME=VALUE` command line override.
+pub fn parse_assignment(arg: &str) -> Result<(&str, i64), String> {
+    let error = || format!("Invalid parameter '{}', expected NAME=VALUE", arg);
+    let (name, value) = arg.split_once('=').ok_or_else(error)?;
+    let value = value.trim().parse::<i64>().map_err(|_| error())?;
+    Ok((name.trim(), value))
+}
+
+/// Parameter overrides of any number of days, read from a file with one
+/// `DAY NAME VALUE` entry per line like `answers.txt`.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Overrides {
+    days: HashMap<u32, Params>,
+}
+
+impl Overrides {
+    pub fn new() -> Overrides {
+        Overrides::default()
+    }
+
+    /// `#` starts a comment line.
+    pub fn parse(input: &str) -> Result<Overrides, ParseError> {
+        let mut ret = Overrides::new();
+        for (y, line) in input.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let [day, name, value] = line.split_whitespace().collect::<Vec<&str>>()[..] else {
+                return Err(ParseError::new(y, 0, "DAY NAME VALUE"));
+            };
+            let day: u32 = parse_in_line(y, line, day, "a day")?;
+            let value: i64 = parse_in_line(y, line, value, "a number")?;
+            ret.set(day, name, value);
+        }
+        Ok(ret)
+    }
+
+    pub fn read(path: &Path) -> Result<Overrides, String> {
+        let input = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Overrides::parse(&input).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn set(&mut self, day: u32, name: &str, value: i64) {
+        self.days.entry(day).or_default().set(name, value);
+    }
+
+    /// Checks the overrides of every day against the parameters it declares.
+    pub fn check(&self) -> Result<(), String> {
+        let mut days = self.days.keys().collect::<Vec<&u32>>();
+        days.sort();
+        for day in days {
+            let declared = match PARAMS.get((*day as usize).wrapping_sub(1)) {
+                Some(declared) => declared,
+                None => return Err(format!("Invalid day {}", day)),
+            };
+            self.days[day]
+                .check(declared)
+                .map_err(|e| format!("Day {}: {}", day, e))?;
+        }
+        Ok(())
+    }
+
+    /// The overrides of `day`, empty when there are none.
+    pub fn day(&self, day: u32) -> Params {
+        self.days.get(&day).cloned().unwrap_or_default()
+    }
+}
+
+/// Lists `params` of `day` in the `Overrides` file format, with the help as a
+/// comment above each default.
+pub struct Listing<'a> {
+    pub day: u32,
+    pub params: &'a [Param],
+}
+
+impl Display for Listing<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, param) in self.params.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "# {}", param.help)?;
+            write!(f, "{} {} {}", self.day, param.name, param.default)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ROUNDS: Param = Param {
+        name: "rounds",
+        default: 2000,
+        min: 0,
+        max: 100_000,
+        help: "Secret numbers generated per buyer",
+    };
+
+    #[test]
+    fn test_params() {
+        let mut params = Params::new();
+        assert!(params.get(&ROUNDS) == 2000);
+        params.set("rounds", 10);
+        assert!(params.get(&ROUNDS) == 10);
+        assert!(params.check(&[ROUNDS]).is_ok());
+
+        params.set("rounds", 100_001);
+        assert!(params.check(&[ROUNDS]) == Err("rounds has to be at most 100000".to_string()));
+        params.set("rounds", -1);
+        assert!(params.check(&[ROUNDS]) == Err("rounds has to be at least 0".to_string()));
+        params.set("round", 1);
+        assert!(params.check(&[ROUNDS]) == Err("Unknown parameter 'round'".to_string()));
+
+        assert!(parse_assignment("width=11") == Ok(("width", 11)));
+        assert!(parse_assignment("width").is_err());
+        assert!(parse_assignment("width=x").is_err());
+
+        let mut sorted = Params::new();
+        sorted.set("width", 11);
+        sorted.set("height", 7);
+        assert!(sorted.entries() == [("height", 7), ("width", 11)]);
+    }
+
+    #[test]
+    fn test_overrides() {
+        let overrides = Overrides::parse("# example\n14 width 11\n14 height 7\n\n22 rounds 10")
+            .expect("Invalid overrides");
+        assert!(overrides.day(22).get(&ROUNDS) == 10);
+        assert!(overrides.day(14).get(&ROUNDS) == 2000);
+        assert!(overrides.day(1).is_empty());
+        assert!(overrides.check() == Ok(()));
+        let err = Overrides::parse("14 width 4000000000").map(|o| o.check());
+        assert!(err == Ok(Err("Day 14: width has to be at most 1000".to_string())));
+        let err = Overrides::parse("26 width 11").map(|o| o.check());
+        assert!(err == Ok(Err("Invalid day 26".to_string())));
+
+        let err = Overrides::parse("14 width").err();
+        assert!(err == Some(ParseError::new(0, 0, "DAY NAME VALUE")));
+        let err = Overrides::parse("14 width x").err();
+        assert!(err == Some(ParseError::new(0, 9, "a number")));
+
+        let listing = Listing {
+            day: 22,
+            params: &[ROUNDS],
+        };
+        assert!(listing.to_string() == "# Secret numbers generated per buyer\n22 rounds 2000");
+        let parsed = Overrides::parse(&listing.to_string()).expect("Invalid listing");
+        assert!(parsed.day(22).get(&ROUNDS) == 2000);
+    }
+}
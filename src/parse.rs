@@ -0,0 +1,275 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Position (1-based) in the puzzle input and what should have been there.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// `line_idx` and `column_idx` are zero based, as returned by `enumerate()`.
+    pub fn new(line_idx: usize, column_idx: usize, expected: &str) -> ParseError {
+        ParseError {
+            line: line_idx + 1,
+            column: column_idx + 1,
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Error pointing at `part`, which has to be a slice of `line`.
+    pub fn in_line(line_idx: usize, line: &str, part: &str, expected: &str) -> ParseError {
+        ParseError::new(line_idx, column_of(line, part), expected)
+    }
+
+    /// Error for something that is missing from the whole input.
+    pub fn at_end(input: &str, expected: &str) -> ParseError {
+        let line_idx = input.lines().count().saturating_sub(1);
+        let column_idx = input.lines().last().map_or(0, |l| l.chars().count());
+        ParseError::new(line_idx, column_idx, expected)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Character index of `part` inside `line`, 0 when it is not a slice of it.
+fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    line.get(..offset)
+        .map_or(0, |before| before.chars().count())
+}
+
+/// Parses `part`, a slice of `line`, and reports its position on failure.
+pub fn parse_in_line<T: FromStr>(
+    line_idx: usize,
+    line: &str,
+    part: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    part.parse::<T>()
+        .map_err(|_| ParseError::in_line(line_idx, line, part, expected))
+}
+
+/// Slices of all integers in `part`. A `-` or `+` right before the digits is
+/// part of the number, so `3-5` yields `3` and `-5`.
+pub fn integer_slices(part: &str) -> impl Iterator<Item = &str> {
+    let bytes = part.as_bytes();
+    let mut idx = 0;
+    std::iter::from_fn(move || {
+        let start = (idx..bytes.len()).find(|i| bytes[*i].is_ascii_digit())?;
+        let end = (start..bytes.len())
+            .find(|i| !bytes[*i].is_ascii_digit())
+            .unwrap_or(bytes.len());
+        idx = end;
+        let signed = start > 0 && matches!(bytes[start - 1], b'-' | b'+');
+        Some(&part[start - signed as usize..end])
+    })
+}
+
+/// All integers in `part` that fit into `T`, for input that is known to be valid.
+pub fn integers<T: FromStr>(part: &str) -> impl Iterator<Item = T> + '_ {
+    integer_slices(part).filter_map(|s| s.parse().ok())
+}
+
+/// All integers in `part`, a slice of `line`.
+pub fn parse_integers<T: FromStr>(
+    line_idx: usize,
+    line: &str,
+    part: &str,
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    integer_slices(part)
+        .map(|s| parse_in_line(line_idx, line, s, expected))
+        .collect()
+}
+
+/// Exactly `N` integers in `part`, a slice of `line`. Missing ones are reported
+/// at the start of `part`, surplus ones where they are.
+pub fn parse_n_integers<T: FromStr, const N: usize>(
+    line_idx: usize,
+    line: &str,
+    part: &str,
+    expected: &str,
+) -> Result<[T; N], ParseError> {
+    let slices = integer_slices(part).collect::<Vec<&str>>();
+    match slices.len() {
+        len if len < N => Err(ParseError::in_line(line_idx, line, part, expected)),
+        len if len > N => Err(ParseError::in_line(line_idx, line, slices[N], expected)),
+        _ => {
+            let values = slices
+                .iter()
+                .map(|s| parse_in_line(line_idx, line, s, expected))
+                .collect::<Result<Vec<T>, ParseError>>()?;
+            Ok(values.try_into().ok().expect("Length was checked"))
+        }
+    }
+}
+
+/// Line format with `{}` placeholders like `p={},{} v={},{}`. The literal text
+/// has to match exactly and a placeholder captures everything up to the next
+/// literal text, so two placeholders need some text between them.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Pattern<'p> {
+    literals: Vec<&'p str>,
+}
+
+impl<'p> Pattern<'p> {
+    /// A pattern without placeholders matches just its text. Panics when two
+    /// placeholders have no text between them.
+    pub fn new(pattern: &'p str) -> Pattern<'p> {
+        let literals = pattern.split("{}").collect::<Vec<&str>>();
+        let inner = literals.get(1..literals.len() - 1).unwrap_or_default();
+        assert!(
+            inner.iter().all(|l| !l.is_empty()),
+            "Placeholders without text between them in '{}'",
+            pattern
+        );
+        Pattern { literals }
+    }
+
+    pub fn placeholders(&self) -> usize {
+        self.literals.len() - 1
+    }
+
+    /// The captured slices of `line`.
+    pub fn captures<'a>(&self, line_idx: usize, line: &'a str) -> Result<Vec<&'a str>, ParseError> {
+        let literal_error = |rest: &str, literal: &str| {
+            let column = line.chars().count() - rest.chars().count();
+            ParseError::new(line_idx, column, &format!("'{}'", literal))
+        };
+        let mut ret = Vec::new();
+        let Some(mut rest) = line.strip_prefix(self.literals[0]) else {
+            return Err(literal_error(line, self.literals[0]));
+        };
+        for literal in self.literals[1..].iter() {
+            let end = match literal.is_empty() {
+                true => rest.len(),
+                false => rest.find(literal).ok_or(literal_error(rest, literal))?,
+            };
+            ret.push(&rest[..end]);
+            rest = &rest[end + literal.len()..];
+        }
+        if !rest.is_empty() {
+            return Err(ParseError::in_line(
+                line_idx,
+                line,
+                rest,
+                "the end of the line",
+            ));
+        }
+        Ok(ret)
+    }
+
+    /// All captures parsed as `T`, `N` has to be the number of placeholders.
+    pub fn parse<T: FromStr, const N: usize>(
+        &self,
+        line_idx: usize,
+        line: &str,
+        expected: &str,
+    ) -> Result<[T; N], ParseError> {
+        assert!(
+            N == self.placeholders(),
+            "Pattern has {} placeholders",
+            self.placeholders()
+        );
+        let values = self
+            .captures(line_idx, line)?
+            .iter()
+            .map(|c| parse_in_line(line_idx, line, c, expected))
+            .collect::<Result<Vec<T>, ParseError>>()?;
+        Ok(values.try_into().ok().expect("Length was checked"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_column() {
+        let line = "12 ab,34";
+        let (_, b) = line.split_once(',').unwrap();
+        assert!(column_of(line, b) == 6);
+        assert!(column_of(line, line) == 0);
+        assert!(column_of(line, "ab") == 0);
+
+        let err = parse_in_line::<i64>(2, line, &line[3..5], "a number").unwrap_err();
+        assert!(err == ParseError::new(2, 3, "a number"));
+        assert!(err.to_string() == "line 3, column 4: expected a number");
+        assert!(parse_in_line::<i64>(2, line, b, "a number") == Ok(34));
+    }
+
+    #[test]
+    fn test_at_end() {
+        let err = ParseError::at_end("ab\ncde\n", "a start tile");
+        assert!(err.line == 2 && err.column == 4);
+        let err = ParseError::at_end("", "a start tile");
+        assert!(err.line == 1 && err.column == 1);
+    }
+
+    #[test]
+    fn test_integers() {
+        let line = "Button A: X+94, Y-34 3-5 x12";
+        let slices = integer_slices(line).collect::<Vec<&str>>();
+        assert!(slices == ["+94", "-34", "3", "-5", "12"]);
+        assert!(integers::<i64>(line).collect::<Vec<i64>>() == [94, -34, 3, -5, 12]);
+        assert!(integers::<u8>("-1 2 300").collect::<Vec<u8>>() == [2]);
+        assert!(integer_slices("no digits - here").next().is_none());
+
+        assert!(parse_integers::<i64>(0, line, line, "a number") == Ok(vec![94, -34, 3, -5, 12]));
+        let err = parse_integers::<u8>(0, "1 -2", "1 -2", "a count").err();
+        assert!(err == Some(ParseError::new(0, 2, "a count")));
+
+        let (_, value) = line.split_once(':').unwrap();
+        let pair = parse_n_integers::<i64, 2>(1, line, &value[..12], "X and Y values");
+        assert!(pair == Ok([94, -34]));
+        let err =
+            parse_n_integers::<i64, 3>(1, "Prize: X=1, Y=2", &"Prize: X=1, Y=2"[6..], "X, Y and Z");
+        assert!(err == Err(ParseError::new(1, 6, "X, Y and Z")));
+        let err = parse_n_integers::<i64, 1>(1, line, line, "a value").err();
+        assert!(err == Some(ParseError::new(1, 17, "a value")));
+    }
+
+    #[test]
+    fn test_pattern() {
+        let robot = Pattern::new("p={},{} v={},{}");
+        assert!(robot.placeholders() == 4);
+        assert!(robot.captures(0, "p=0,4 v=3,-3") == Ok(vec!["0", "4", "3", "-3"]));
+        assert!(robot.parse::<i64, 4>(0, "p=10,3 v=-1,2", "a number") == Ok([10, 3, -1, 2]));
+
+        let err = robot.parse::<i64, 4>(2, "p=1,x v=3,3", "a number").err();
+        assert!(err == Some(ParseError::new(2, 4, "a number")));
+        let err = robot.captures(2, "p=1,2 w=3,3").err();
+        assert!(err == Some(ParseError::new(2, 4, "' v='")));
+        let err = robot.captures(2, "q=1,2").err();
+        assert!(err == Some(ParseError::new(2, 0, "'p='")));
+
+        let gate = Pattern::new("{} -> {}.");
+        assert!(gate.captures(0, "x AND y -> z.") == Ok(vec!["x AND y", "z"]));
+        let err = gate.captures(0, "x -> z. rest").err();
+        assert!(err == Some(ParseError::new(0, 7, "the end of the line")));
+
+        let plain = Pattern::new("done");
+        assert!(plain.placeholders() == 0);
+        assert!(plain.captures(0, "done") == Ok(vec![]));
+        assert!(plain.captures(0, "done!").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Placeholders without text between them")]
+    fn test_pattern_adjacent_placeholders() {
+        Pattern::new("{}{}");
+    }
+}
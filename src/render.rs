@@ -0,0 +1,293 @@
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use num::PrimInt;
+
+use crate::image::Rgb;
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::utils::{Grid, PointT};
+
+/// Moves the cursor home, the frame is then drawn over the previous one.
+const HOME: &str = "\x1b[H";
+const CLEAR_SCREEN: &str = "\x1b[2J";
+const CLEAR_TO_END: &str = "\x1b[J";
+const RESET: &str = "\x1b[0m";
+
+/// Foreground colour of a cell, `Plain` keeps the terminal default.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Colour {
+    Plain,
+    Grey,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    fn code(self) -> &'static str {
+        match self {
+            Colour::Plain => "39",
+            Colour::Grey => "90",
+            Colour::Red => "31",
+            Colour::Green => "32",
+            Colour::Yellow => "33",
+            Colour::Blue => "34",
+            Colour::Magenta => "35",
+            Colour::Cyan => "36",
+        }
+    }
+    /// Colour in images, `Plain` is the black background.
+    pub fn rgb(self) -> Rgb {
+        match self {
+            Colour::Plain => [0, 0, 0],
+            Colour::Grey => [128, 128, 128],
+            Colour::Red => [220, 40, 40],
+            Colour::Green => [40, 200, 60],
+            Colour::Yellow => [230, 200, 40],
+            Colour::Blue => [50, 90, 230],
+            Colour::Magenta => [200, 60, 200],
+            Colour::Cyan => [40, 200, 220],
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Cell {
+    pub glyph: char,
+    pub colour: Colour,
+}
+
+impl Cell {
+    pub fn new(glyph: char, colour: Colour) -> Cell {
+        Cell { glyph, colour }
+    }
+
+    pub fn plain(glyph: char) -> Cell {
+        Cell::new(glyph, Colour::Plain)
+    }
+}
+
+/// One picture of a simulation: a grid of coloured glyphs and a caption below it.
+/// `Display` writes the glyphs only, `to_ansi` adds the colours.
+pub struct Frame {
+    cells: Grid<Cell>,
+    caption: String,
+}
+
+impl Frame {
+    /// Frame showing every cell of `grid` as `cell` returns it.
+    pub fn new<T, F: FnMut(&T) -> Cell>(grid: &Grid<T>, cell: F) -> Frame {
+        Frame {
+            cells: grid.map(cell),
+            caption: String::new(),
+        }
+    }
+
+    pub fn blank(width: usize, height: usize, cell: Cell) -> Frame {
+        Frame {
+            cells: Grid::new(width, height, cell),
+            caption: String::new(),
+        }
+    }
+
+    pub fn with_caption<S: Into<String>>(mut self, caption: S) -> Frame {
+        self.caption = caption.into();
+        self
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    pub fn get<I: PrimInt>(&self, p: &PointT<I>) -> Option<&Cell> {
+        self.cells.get(p)
+    }
+
+    /// Draws `cell` at `p`, points outside of the frame are ignored.
+    pub fn set<I: PrimInt>(&mut self, p: &PointT<I>, cell: Cell) {
+        if let Some(c) = self.cells.get_mut(p) {
+            *c = cell;
+        }
+    }
+
+    /// Draws `cell` at all `points`.
+    pub fn overlay<I, P>(&mut self, points: P, cell: Cell)
+    where
+        I: PrimInt,
+        P: IntoIterator<Item = PointT<I>>,
+    {
+        for p in points {
+            self.set(&p, cell);
+        }
+    }
+
+    /// The frame with ANSI colour escapes, the colour is only switched where it changes.
+    pub fn to_ansi(&self) -> String {
+        let mut ret = String::new();
+        let mut colour = Colour::Plain;
+        for row in self.cells.rows() {
+            for cell in row {
+                if cell.colour != colour {
+                    colour = cell.colour;
+                    ret.push_str(&format!("\x1b[{}m", colour.code()));
+                }
+                ret.push(cell.glyph);
+            }
+            ret.push('\n');
+        }
+        ret.push_str(RESET);
+        ret.push_str(&self.caption);
+        ret
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.cells.map(|c| c.glyph), self.caption)
+    }
+}
+
+/// A simulation that can be shown frame by frame.
+pub trait Render {
+    /// The current state.
+    fn frame(&self) -> Frame;
+    /// Moves on to the next frame, `false` once the simulation is over.
+    fn advance(&mut self) -> bool;
+}
+
+/// Shows a `Render` in the terminal.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Player {
+    /// Frames per second, 0 plays as fast as possible.
+    pub fps: f64,
+    /// Stops after this many frames, endless simulations need a limit.
+    pub max_frames: Option<usize>,
+}
+
+impl Player {
+    pub fn new(fps: f64) -> Player {
+        Player {
+            fps,
+            max_frames: None,
+        }
+    }
+
+    fn delay(&self) -> Duration {
+        if self.fps > 0.0 {
+            Duration::from_secs_f64(1.0 / self.fps)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// Draws the frames of `sim` over each other until it is over, returns the
+    /// number of frames shown.
+    pub fn play<W: Write>(&self, sim: &mut dyn Render, out: &mut W) -> io::Result<usize> {
+        let mut shown = 0;
+        write!(out, "{}", CLEAR_SCREEN)?;
+        loop {
+            write!(out, "{}{}{}", HOME, sim.frame().to_ansi(), CLEAR_TO_END)?;
+            out.flush()?;
+            shown += 1;
+            if self.max_frames.is_some_and(|max| shown >= max) || !sim.advance() {
+                break;
+            }
+            thread::sleep(self.delay());
+        }
+        writeln!(out)?;
+        Ok(shown)
+    }
+}
+
+/// Parses an input into a simulation that can be played.
+pub type Animation = fn(&str, &Params) -> Result<Box<dyn Render>, ParseError>;
+
+/// The animation of `day`, if it has one.
+pub fn animation(day: u32) -> Option<Animation> {
+    match day {
+        6 => Some(crate::day06::animation),
+        14 => Some(crate::day14::animation),
+        18 => Some(crate::day18::animation),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Counter {
+        count: usize,
+    }
+
+    impl Render for Counter {
+        fn frame(&self) -> Frame {
+            let mut frame = Frame::blank(3, 1, Cell::plain('.'));
+            frame.set(
+                &PointT {
+                    x: self.count as i32,
+                    y: 0,
+                },
+                Cell::new('o', Colour::Red),
+            );
+            frame.with_caption(format!("{}", self.count))
+        }
+
+        fn advance(&mut self) -> bool {
+            self.count += 1;
+            self.count < 3
+        }
+    }
+
+    #[test]
+    fn test_frame() {
+        let grid = Grid::parse_chars("#.\n.#").expect("Invalid grid");
+        let mut frame = Frame::new(&grid, |c| match c {
+            '#' => Cell::new('#', Colour::Grey),
+            c => Cell::plain(*c),
+        });
+        frame.overlay(
+            [PointT { x: 1, y: 0 }, PointT { x: 5, y: 5 }],
+            Cell::new('X', Colour::Yellow),
+        );
+        let frame = frame.with_caption("step 1");
+        assert!(frame.to_string() == "#X\n.#\nstep 1");
+        assert!(frame.get(&PointT { x: 1, y: 0 }) == Some(&Cell::new('X', Colour::Yellow)));
+        assert!(frame.to_ansi() == "\x1b[90m#\x1b[33mX\n\x1b[39m.\x1b[90m#\n\x1b[0mstep 1");
+    }
+
+    #[test]
+    fn test_player() {
+        let mut out = Vec::new();
+        let shown = Player::new(0.0)
+            .play(&mut Counter { count: 0 }, &mut out)
+            .expect("Write failed");
+        assert!(shown == 3);
+        let out = String::from_utf8(out).expect("Not UTF-8");
+        assert!(out.matches(HOME).count() == 3);
+        assert!(out.contains("..\x1b[31mo\n\x1b[0m2"));
+
+        let player = Player {
+            fps: 0.0,
+            max_frames: Some(2),
+        };
+        let shown = player.play(&mut Counter { count: 0 }, &mut Vec::new());
+        assert!(shown.ok() == Some(2));
+    }
+
+    #[test]
+    fn test_animations() {
+        assert!(animation(1).is_none());
+        let input = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
+        let mut sim = animation(6).expect("Day 6 has an animation")(input, &Params::new())
+            .expect("Invalid input");
+        while sim.advance() {}
+        assert!(sim.frame().to_string().ends_with("41 positions visited"));
+    }
+}
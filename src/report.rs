@@ -0,0 +1,469 @@
+use std::any::Any;
+use std::panic;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use crate::cache::{input_hash, Cache};
+use crate::cancel::Token;
+use crate::input::InputSource;
+use crate::params::{Overrides, Params};
+use crate::parse::ParseError;
+use crate::solution::{Answers, SolveError, DAYS};
+
+const CSV_HEADER: &str = "day,status,part1,part2,parse_ns,part1_ns,part2_ns,error";
+
+/// How `aoc run` prints its results, `Json` writes one object per line.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format '{}'", s)),
+        }
+    }
+}
+
+/// What happened when a day was run.
+pub enum Outcome {
+    Solved(Answers),
+    /// Served from the cache, the run times are all zero.
+    Cached(Answers),
+    InputError(String),
+    ParseError(ParseError),
+    /// The solver panicked, holds the panic message.
+    Panicked(String),
+    /// The time budget ran out or the run was cancelled, holds which part gave up.
+    GaveUp(String),
+}
+
+pub struct Record {
+    pub day: u32,
+    pub outcome: Outcome,
+}
+
+impl Record {
+    /// Reads the input of `day` from `source` and solves it, a panic in the
+    /// solver is caught and recorded. With a `cache` the answers found before
+    /// are used and new ones are added to it.
+    pub fn run(
+        day: u32,
+        source: &InputSource,
+        params: &Params,
+        token: &Token,
+        cache: Option<&Cache>,
+    ) -> Record {
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) => {
+                let outcome = Outcome::InputError(e.to_string());
+                return Record { day, outcome };
+            }
+        };
+        let hash = input_hash(&input, params);
+        if let Some((part1, part2)) = cache.and_then(|c| c.get(day, hash)) {
+            let answers = Answers {
+                day,
+                part1,
+                part2,
+                parse_time: Duration::ZERO,
+                part1_time: Duration::ZERO,
+                part2_time: Duration::ZERO,
+            };
+            let outcome = Outcome::Cached(answers);
+            return Record { day, outcome };
+        }
+        let outcome = match catch_panic(|| DAYS[day as usize - 1](&input, params, token)) {
+            Ok(Ok(answers)) => {
+                if let Some(cache) = cache {
+                    cache.insert(&answers, hash);
+                }
+                Outcome::Solved(answers)
+            }
+            Ok(Err(SolveError::Parse(e))) => Outcome::ParseError(e),
+            Ok(Err(e)) => Outcome::GaveUp(e.to_string()),
+            Err(message) => Outcome::Panicked(message),
+        };
+        Record { day, outcome }
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self.outcome {
+            Outcome::Solved(_) => "ok",
+            Outcome::Cached(_) => "cached",
+            Outcome::InputError(_) => "input_error",
+            Outcome::ParseError(_) => "parse_error",
+            Outcome::Panicked(_) => "panicked",
+            Outcome::GaveUp(_) => "gave_up",
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        matches!(self.outcome, Outcome::Solved(_) | Outcome::Cached(_))
+    }
+
+    pub fn error(&self) -> Option<String> {
+        match &self.outcome {
+            Outcome::Solved(_) | Outcome::Cached(_) => None,
+            Outcome::InputError(e) => Some(e.clone()),
+            Outcome::ParseError(e) => Some(e.to_string()),
+            Outcome::Panicked(message) => Some(format!("panicked: {}", message)),
+            Outcome::GaveUp(message) => Some(message.clone()),
+        }
+    }
+
+    pub fn answers(&self) -> Option<&Answers> {
+        match &self.outcome {
+            Outcome::Solved(answers) | Outcome::Cached(answers) => Some(answers),
+            _ => None,
+        }
+    }
+
+    /// The answers of a day that was actually solved, the only ones with run times.
+    fn timed(&self) -> Option<&Answers> {
+        match &self.outcome {
+            Outcome::Solved(answers) => Some(answers),
+            _ => None,
+        }
+    }
+}
+
+type PanicHook = Box<dyn Fn(&panic::PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+/// How many `catch_panic` calls are running and the hook they replaced.
+static QUIET_PANICS: Mutex<(usize, Option<PanicHook>)> = Mutex::new((0, None));
+
+/// Runs `f`, a panic in it is caught and returned as its message. Panics print
+/// nothing while any `catch_panic` runs, the caller reports the message.
+pub fn catch_panic<T>(f: impl FnOnce() -> T + panic::UnwindSafe) -> Result<T, String> {
+    set_quiet_panics(true);
+    let ret = panic::catch_unwind(f);
+    set_quiet_panics(false);
+    ret.map_err(panic_message)
+}
+
+/// The first caller installs a silent panic hook, the last one to leave puts
+/// the previous hook back.
+fn set_quiet_panics(quiet: bool) {
+    let mut state = QUIET_PANICS.lock().expect("Poisoned panic hook");
+    let (running, previous) = &mut *state;
+    if quiet {
+        if *running == 0 {
+            *previous = Some(panic::take_hook());
+            panic::set_hook(Box::new(|_| {}));
+        }
+        *running += 1;
+    } else {
+        *running -= 1;
+        if *running == 0 {
+            if let Some(hook) = previous.take() {
+                panic::set_hook(hook);
+            }
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+/// Runs `days` on `jobs` worker threads, the records are sorted by day. Each
+/// day gets its own `timeout`, all share the `cache`.
+pub fn run_parallel(
+    days: &[u32],
+    input: Option<&str>,
+    overrides: &Overrides,
+    timeout: Option<Duration>,
+    cache: Option<&Cache>,
+    jobs: usize,
+) -> Vec<Record> {
+    let next = AtomicUsize::new(0);
+    let records = Mutex::new(Vec::new());
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            s.spawn(|| {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let source = InputSource::from_arg(*day, input);
+                    let token = timeout.map_or_else(Token::unlimited, Token::with_budget);
+                    let record = Record::run(*day, &source, &overrides.day(*day), &token, cache);
+                    records.lock().expect("Poisoned record list").push(record);
+                }
+            });
+        }
+    });
+    let mut records = records.into_inner().expect("Poisoned record list");
+    records.sort_by_key(|r| r.day);
+    records
+}
+
+/// One aligned table of all `records`, failed days show their error instead of
+/// the answers.
+pub fn table(records: &[Record]) -> String {
+    let header = ["Day", "Status", "Part1", "Part2", "Time"].map(String::from);
+    let rows = records
+        .iter()
+        .map(|r| match (r.answers(), r.error()) {
+            (Some(a), _) => [
+                r.day.to_string(),
+                r.status().to_string(),
+                a.part1.clone(),
+                a.part2.clone(),
+                r.timed()
+                    .map_or("-".to_string(), |a| format!("{:.2?}", a.elapsed())),
+            ],
+            (None, error) => [
+                r.day.to_string(),
+                r.status().to_string(),
+                error.unwrap_or_default(),
+                String::new(),
+                String::new(),
+            ],
+        })
+        .collect::<Vec<[String; 5]>>();
+    let mut widths = header.clone().map(|h| h.len());
+    for row in rows.iter() {
+        // errors are not aligned, they may run over the answer columns
+        let columns = if row[4].is_empty() { 2 } else { 5 };
+        for (w, cell) in widths.iter_mut().zip(row.iter()).take(columns) {
+            *w = (*w).max(cell.chars().count());
+        }
+    }
+    let mut ret = String::new();
+    for row in [header].iter().chain(rows.iter()) {
+        let line = format!(
+            "{:>w0$}  {:<w1$}  {:<w2$}  {:<w3$}  {:>w4$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+        ret.push_str(line.trim_end());
+        ret.push('\n');
+    }
+    let ok = records.iter().filter(|r| r.is_ok()).count();
+    let total = records
+        .iter()
+        .filter_map(|r| r.timed())
+        .map(|a| a.elapsed())
+        .sum::<Duration>();
+    ret.push_str(&format!(
+        "{} ok, {} failed, total run time {:.2?}",
+        ok,
+        records.len() - ok,
+        total
+    ));
+    ret
+}
+
+impl Format {
+    /// Printed once before the first record.
+    pub fn header(&self) -> Option<&'static str> {
+        match self {
+            Format::Csv => Some(CSV_HEADER),
+            _ => None,
+        }
+    }
+
+    pub fn record(&self, record: &Record) -> String {
+        match self {
+            Format::Text => text_record(record),
+            Format::Json => json_record(record),
+            Format::Csv => csv_record(record),
+        }
+    }
+}
+
+fn text_record(record: &Record) -> String {
+    match (record.answers(), record.error()) {
+        (Some(answers), _) if record.timed().is_none() => format!(
+            "Day {:02} (cached)\nPart1: {}\nPart2: {}",
+            record.day, answers.part1, answers.part2
+        ),
+        (Some(answers), _) => format!("Day {:02}\n{}", record.day, answers),
+        (None, error) => format!("Day {}: {}", record.day, error.unwrap_or_default()),
+    }
+}
+
+fn json_record(record: &Record) -> String {
+    let answers = record.answers();
+    let part =
+        |f: fn(&Answers) -> &String| answers.map_or("null".to_string(), |a| json_string(f(a)));
+    let timed = record.timed();
+    let time = |f: fn(&Answers) -> Duration| timed.map_or("null".to_string(), |a| nanos(f(a)));
+    format!(
+        "{{\"day\":{},\"status\":\"{}\",\"part1\":{},\"part2\":{},\"parse_ns\":{},\"part1_ns\":{},\"part2_ns\":{},\"error\":{}}}",
+        record.day,
+        record.status(),
+        part(|a| &a.part1),
+        part(|a| &a.part2),
+        time(|a| a.parse_time),
+        time(|a| a.part1_time),
+        time(|a| a.part2_time),
+        record.error().map_or("null".to_string(), |e| json_string(&e)),
+    )
+}
+
+fn csv_record(record: &Record) -> String {
+    let answers = record.answers();
+    let part = |f: fn(&Answers) -> &String| answers.map_or(String::new(), |a| csv_field(f(a)));
+    let timed = record.timed();
+    let time = |f: fn(&Answers) -> Duration| timed.map_or(String::new(), |a| nanos(f(a)));
+    [
+        record.day.to_string(),
+        record.status().to_string(),
+        part(|a| &a.part1),
+        part(|a| &a.part2),
+        time(|a| a.parse_time),
+        time(|a| a.part1_time),
+        time(|a| a.part2_time),
+        record.error().map_or(String::new(), |e| csv_field(&e)),
+    ]
+    .join(",")
+}
+
+fn nanos(d: Duration) -> String {
+    d.as_nanos().to_string()
+}
+
+fn json_string(s: &str) -> String {
+    let mut ret = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            '\r' => ret.push_str("\\r"),
+            '\t' => ret.push_str("\\t"),
+            c if (c as u32) < 0x20 => ret.push_str(&format!("\\u{:04x}", c as u32)),
+            c => ret.push(c),
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn solved() -> Record {
+        Record {
+            day: 17,
+            outcome: Outcome::Solved(Answers {
+                day: 17,
+                part1: "3,5,0".to_string(),
+                part2: "42".to_string(),
+                parse_time: Duration::from_nanos(10),
+                part1_time: Duration::from_nanos(20),
+                part2_time: Duration::from_nanos(30),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        assert!(
+            Format::Json.record(&solved())
+                == r#"{"day":17,"status":"ok","part1":"3,5,0","part2":"42","parse_ns":10,"part1_ns":20,"part2_ns":30,"error":null}"#
+        );
+        let failed = Record {
+            day: 3,
+            outcome: Outcome::ParseError(ParseError::new(0, 4, "a \"mul\"")),
+        };
+        assert!(
+            Format::Json.record(&failed)
+                == r#"{"day":3,"status":"parse_error","part1":null,"part2":null,"parse_ns":null,"part1_ns":null,"part2_ns":null,"error":"line 1, column 5: expected a \"mul\""}"#
+        );
+        assert!(json_string("a\\b\n\u{1}") == r#""a\\b\n\u0001""#);
+    }
+
+    #[test]
+    fn test_csv() {
+        assert!(Format::Csv.header() == Some(CSV_HEADER));
+        assert!(Format::Csv.record(&solved()) == "17,ok,\"3,5,0\",42,10,20,30,");
+        let failed = Record {
+            day: 9,
+            outcome: Outcome::InputError("missing".to_string()),
+        };
+        assert!(Format::Csv.record(&failed) == "9,input_error,,,,,,missing");
+        assert!(csv_field("say \"hi\"") == "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_panic_and_table() {
+        let token = Token::unlimited();
+        let record = Record::run(15, &InputSource::Default(15), &Params::new(), &token, None);
+        assert!(record.status() == "panicked");
+        assert!(record.error() == Some("panicked: not yet implemented: finish day15".to_string()));
+
+        let records = [solved(), record];
+        let table = table(&records);
+        let lines = table.lines().collect::<Vec<&str>>();
+        assert!(lines.len() == 4);
+        assert!(lines[0].starts_with("Day  Status"));
+        assert!(lines[1].starts_with(" 17  ok        3,5,0  42"));
+        assert!(lines[2].starts_with(" 15  panicked  panicked: not yet implemented"));
+        assert!(lines[3].starts_with("1 ok, 1 failed"));
+    }
+
+    #[test]
+    fn test_run_parallel() {
+        let records = run_parallel(&[5, 2, 15, 1], None, &Overrides::new(), None, None, 3);
+        assert!(records.iter().map(|r| r.day).collect::<Vec<u32>>() == [1, 2, 5, 15]);
+        assert!(records.iter().filter(|r| r.is_ok()).count() == 3);
+    }
+
+    #[test]
+    fn test_cached() {
+        let cache = Cache::new("cache.txt".as_ref());
+        let token = Token::unlimited();
+        let source = InputSource::Default(1);
+        let record = Record::run(1, &source, &Params::new(), &token, Some(&cache));
+        assert!(record.status() == "ok" && cache.len() == 2);
+        let cached = Record::run(1, &source, &Params::new(), &token, Some(&cache));
+        assert!(cached.status() == "cached" && cached.is_ok());
+        assert!(cached.answers().map(|a| &a.part1) == record.answers().map(|a| &a.part1));
+        assert!(Format::Json.record(&cached).contains(r#""parse_ns":null"#));
+        assert!(Format::Text
+            .record(&cached)
+            .starts_with("Day 01 (cached)\nPart1: "));
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert!("csv".parse::<Format>() == Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
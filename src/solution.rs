@@ -0,0 +1,239 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use crate::cancel::Token;
+use crate::event;
+use crate::input::InputSource;
+use crate::params::{Param, Params};
+use crate::parse::ParseError;
+use crate::trace;
+
+/// What a part returns, days whose parts can give up answer with an `Option`.
+pub trait Answer {
+    /// The answer as shown, `None` when the solver gave up.
+    fn answer(&self) -> Option<String>;
+}
+
+macro_rules! answer_by_display {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn answer(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+        })*
+    };
+}
+
+answer_by_display!(i64, i128, usize, String);
+
+impl<T: Answer> Answer for Option<T> {
+    fn answer(&self) -> Option<String> {
+        self.as_ref()?.answer()
+    }
+}
+
+/// Common interface of all puzzle days.
+pub trait Solution: Sized {
+    const DAY: u32;
+    type Part1: Answer;
+    type Part2: Answer;
+    /// The knobs of the puzzle that `parse_with` reads.
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+    /// Parses `input` for a variant of the puzzle, days with `PARAMS` override this.
+    fn parse_with(input: &str, params: &Params) -> Result<Self, ParseError> {
+        let _ = params;
+        Self::parse(input)
+    }
+    fn part1(&mut self) -> Self::Part1;
+    fn part2(&mut self) -> Self::Part2;
+    /// `part1` for solvers that may run for very long, they stop when `token`
+    /// expires and answer `None`.
+    fn part1_within(&mut self, token: &Token) -> Self::Part1 {
+        let _ = token;
+        self.part1()
+    }
+    /// `part2` for solvers that may run for very long, see `part1_within`.
+    fn part2_within(&mut self, token: &Token) -> Self::Part2 {
+        let _ = token;
+        self.part2()
+    }
+}
+
+pub struct Answers {
+    pub day: u32,
+    pub part1: String,
+    pub part2: String,
+    pub parse_time: Duration,
+    pub part1_time: Duration,
+    pub part2_time: Duration,
+}
+
+impl Answers {
+    pub fn elapsed(&self) -> Duration {
+        self.parse_time + self.part1_time + self.part2_time
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Part1: {}", self.part1)?;
+        writeln!(f, "Part2: {}", self.part2)?;
+        write!(f, "Run time: {:?}", self.elapsed())
+    }
+}
+
+/// Why a day has no answers.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// The token expired while `part` was running or it found no answer,
+    /// `elapsed` is counted from the start of the parse. `part1` holds the
+    /// answer of part1 when part2 gave up.
+    GaveUp {
+        part: u32,
+        elapsed: Duration,
+        part1: Option<String>,
+    },
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> SolveError {
+        SolveError::Parse(e)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::GaveUp { part, elapsed, .. } => {
+                write!(f, "gave up on part{} after {:.2?}", part, elapsed)
+            }
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// Solves `input` with the `params` overrides, they have to be checked against
+/// `S::PARAMS` before. Gives up when `token` expires in a part that checks it.
+pub fn solve<S: Solution>(
+    input: &str,
+    params: &Params,
+    token: &Token,
+) -> Result<Answers, SolveError> {
+    let start_time = Instant::now();
+    let mut day = S::parse_with(input, params)?;
+    let parse_time = start_time.elapsed();
+    let gave_up = |part, part1| SolveError::GaveUp {
+        part,
+        elapsed: start_time.elapsed(),
+        part1,
+    };
+    let part_start = Instant::now();
+    let part1 = day.part1_within(token).answer();
+    let part1_time = part_start.elapsed();
+    let Some(part1) = part1.filter(|_| !token.gave_up()) else {
+        return Err(gave_up(1, None));
+    };
+    let part_start = Instant::now();
+    let part2 = day.part2_within(token).answer();
+    let part2_time = part_start.elapsed();
+    let Some(part2) = part2.filter(|_| !token.gave_up()) else {
+        return Err(gave_up(2, Some(part1)));
+    };
+    event!(
+        Info,
+        "day={} parse={:?} part1={:?} part2={:?}",
+        S::DAY,
+        parse_time,
+        part1_time,
+        part2_time
+    );
+    Ok(Answers {
+        day: S::DAY,
+        part1,
+        part2,
+        parse_time,
+        part1_time,
+        part2_time,
+    })
+}
+
+/// Entry point of the single day binaries, the optional first argument is
+/// the input file (`-` for stdin). Events are traced as `AOC_TRACE` says.
+pub fn run<S: Solution>() -> Result<(), Box<dyn Error>> {
+    trace::init_from_env()?;
+    let arg = env::args().nth(1);
+    let input = InputSource::from_arg(S::DAY, arg.as_deref()).read()?;
+    println!(
+        "{}",
+        solve::<S>(&input, &Params::new(), &Token::unlimited())?
+    );
+    Ok(())
+}
+
+/// Parses and solves one day's input.
+pub type Solver = fn(&str, &Params, &Token) -> Result<Answers, SolveError>;
+
+/// All days in calendar order, `DAYS[0]` is day 1.
+pub const DAYS: [Solver; 25] = [
+    solve::<crate::day01::Day01>,
+    solve::<crate::day02::Day02>,
+    solve::<crate::day03::Day03>,
+    solve::<crate::day04::Day04>,
+    solve::<crate::day05::Day05>,
+    solve::<crate::day06::Day06>,
+    solve::<crate::day07::Day07>,
+    solve::<crate::day08::Day08>,
+    solve::<crate::day09::Day09>,
+    solve::<crate::day10::Day10>,
+    solve::<crate::day11::Day11>,
+    solve::<crate::day12::Day12>,
+    solve::<crate::day13::Day13>,
+    solve::<crate::day14::Day14>,
+    solve::<crate::day15::Day15>,
+    solve::<crate::day16::Day16>,
+    solve::<crate::day17::Day17>,
+    solve::<crate::day18::Day18>,
+    solve::<crate::day19::Day19>,
+    solve::<crate::day20::Day20>,
+    solve::<crate::day21::Day21>,
+    solve::<crate::day22::Day22>,
+    solve::<crate::day23::Day23>,
+    solve::<crate::day24::Day24>,
+    solve::<crate::day25::Day25>,
+];
+
+/// The parameters of all days, `PARAMS[0]` is day 1.
+pub const PARAMS: [&[Param]; 25] = [
+    crate::day01::Day01::PARAMS,
+    crate::day02::Day02::PARAMS,
+    crate::day03::Day03::PARAMS,
+    crate::day04::Day04::PARAMS,
+    crate::day05::Day05::PARAMS,
+    crate::day06::Day06::PARAMS,
+    crate::day07::Day07::PARAMS,
+    crate::day08::Day08::PARAMS,
+    crate::day09::Day09::PARAMS,
+    crate::day10::Day10::PARAMS,
+    crate::day11::Day11::PARAMS,
+    crate::day12::Day12::PARAMS,
+    crate::day13::Day13::PARAMS,
+    crate::day14::Day14::PARAMS,
+    crate::day15::Day15::PARAMS,
+    crate::day16::Day16::PARAMS,
+    crate::day17::Day17::PARAMS,
+    crate::day18::Day18::PARAMS,
+    crate::day19::Day19::PARAMS,
+    crate::day20::Day20::PARAMS,
+    crate::day21::Day21::PARAMS,
+    crate::day22::Day22::PARAMS,
+    crate::day23::Day23::PARAMS,
+    crate::day24::Day24::PARAMS,
+    crate::day25::Day25::PARAMS,
+];
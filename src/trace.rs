@@ -0,0 +1,243 @@
+use std::env;
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, RwLock};
+
+use lazy_static::lazy_static;
+
+/// Environment variable holding the `Filter`, e.g. `AOC_TRACE=day16=debug`.
+pub const ENV_VAR: &str = "AOC_TRACE";
+
+/// How detailed an event is, `Trace` being the most chatty.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Level, String> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("Invalid trace level '{}'", s)),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Which events are written, parsed from a comma separated list of a level
+/// for all targets and `TARGET=LEVEL` entries like `info,day16=trace`. A
+/// target is a module path below the crate (`day10`, `utils::search`) and
+/// covers the modules inside it, `off` silences it.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Option<Level>)>,
+}
+
+fn parse_level(s: &str) -> Result<Option<Level>, String> {
+    match s {
+        "off" => Ok(None),
+        s => Ok(Some(s.parse()?)),
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Filter, String> {
+        let mut ret = Filter::default();
+        for entry in s.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match entry.split_once('=') {
+                Some((target, level)) => ret
+                    .targets
+                    .push((target.trim().to_string(), parse_level(level.trim())?)),
+                None => ret.default = parse_level(entry)?,
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl Filter {
+    /// The most detailed level written for `target`, `None` if it is silenced.
+    /// The longest matching entry wins, of equally long ones the last.
+    pub fn level(&self, target: &str) -> Option<Level> {
+        self.targets
+            .iter()
+            .filter(|(t, _)| {
+                target == t
+                    || (target.starts_with(t.as_str()) && target[t.len()..].starts_with("::"))
+            })
+            .max_by_key(|(t, _)| t.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    pub fn enabled(&self, target: &str, level: Level) -> bool {
+        self.level(target).is_some_and(|max| level <= max)
+    }
+
+    /// The most detailed level written for any target.
+    fn max_level(&self) -> Option<Level> {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .chain([self.default])
+            .max()
+            .flatten()
+    }
+}
+
+/// `max_level` of the current filter as a number, 0 when nothing is written.
+/// Lets disabled events return without taking the lock.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+lazy_static! {
+    static ref FILTER: RwLock<Filter> = RwLock::new(Filter::default());
+    static ref SINK: Mutex<Box<dyn Write + Send>> = Mutex::new(Box::new(io::stderr()));
+}
+
+/// Replaces the filter of all following events, nothing is written by default.
+pub fn set_filter(filter: Filter) {
+    let max = filter.max_level().map_or(0, |level| level as u8);
+    *FILTER.write().expect("Poisoned trace filter") = filter;
+    MAX_LEVEL.store(max, Ordering::Relaxed);
+}
+
+/// Sets the filter from `AOC_TRACE` if it is set.
+pub fn init_from_env() -> Result<(), String> {
+    match env::var(ENV_VAR) {
+        Ok(spec) => {
+            set_filter(spec.parse().map_err(|e| format!("{}: {}", ENV_VAR, e))?);
+            Ok(())
+        }
+        Err(_) => Ok(()),
+    }
+}
+
+/// Writes the events to `sink` instead of stderr, returns the previous sink.
+pub fn set_sink(sink: Box<dyn Write + Send>) -> Box<dyn Write + Send> {
+    std::mem::replace(&mut *SINK.lock().expect("Poisoned trace sink"), sink)
+}
+
+/// The target of events from `module_path`, which is the path without the crate.
+pub fn target(module_path: &str) -> &str {
+    module_path
+        .split_once("::")
+        .map_or(module_path, |(_, rest)| rest)
+}
+
+/// Whether an event of `level` from `module_path` would be written, used by `event!`.
+pub fn enabled(module_path: &str, level: Level) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    FILTER
+        .read()
+        .expect("Poisoned trace filter")
+        .enabled(target(module_path), level)
+}
+
+/// Writes one event as `[LEVEL target] message`, used by `event!`.
+pub fn emit(module_path: &str, level: Level, message: fmt::Arguments) {
+    let mut sink = SINK.lock().expect("Poisoned trace sink");
+    // tracing must never break the solver, a failed write is dropped
+    let _ = writeln!(sink, "[{} {}] {}", level, target(module_path), message);
+}
+
+/// Writes an event of the calling module if the filter lets it through, the
+/// message is only formatted then: `event!(Debug, "trail end at {}", p)`.
+#[macro_export]
+macro_rules! event {
+    ($level:ident, $($arg:tt)+) => {
+        if $crate::trace::enabled(module_path!(), $crate::trace::Level::$level) {
+            $crate::trace::emit(
+                module_path!(),
+                $crate::trace::Level::$level,
+                format_args!($($arg)+),
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_filter() {
+        let filter = "info, day16=trace,utils::search=off"
+            .parse::<Filter>()
+            .expect("Invalid filter");
+        assert!(filter.level("day10") == Some(Level::Info));
+        assert!(filter.level("day16") == Some(Level::Trace));
+        assert!(filter.level("day160") == Some(Level::Info));
+        assert!(filter.level("utils::search::inner").is_none());
+        assert!(filter.enabled("day10", Level::Warn));
+        assert!(!filter.enabled("day10", Level::Debug));
+        assert!(filter.max_level() == Some(Level::Trace));
+
+        // later entries win, so a filter can be appended to
+        let filter = "day16=debug,day16=off".parse::<Filter>().expect("Invalid");
+        assert!(filter.level("day16").is_none());
+        assert!(Filter::default().max_level().is_none());
+        assert!("day16=loud".parse::<Filter>().is_err());
+        assert!(target("aoc24rust::utils::search") == "utils::search");
+    }
+
+    /// Collects the events, the other tests keep running in parallel.
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0
+                .lock()
+                .expect("Poisoned buffer")
+                .extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_event() {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let previous = set_sink(Box::new(Shared(buffer.clone())));
+        // only this module, events of the days tested in parallel stay silent
+        set_filter("trace::test=debug".parse().expect("Invalid filter"));
+        event!(Debug, "popped distance={}", 3);
+        event!(Trace, "not written");
+        set_filter(Filter::default());
+        event!(Error, "not written either");
+        set_sink(previous);
+        let written = String::from_utf8(buffer.lock().expect("Poisoned").clone());
+        assert!(written.ok().as_deref() == Some("[DEBUG trace::test] popped distance=3\n"));
+    }
+}
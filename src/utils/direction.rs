@@ -0,0 +1,179 @@
+use std::ops;
+
+use num::Signed;
+
+use super::PointT;
+
+/// Compass direction on a map with `y` growing downwards, so `North` is `(0, -1)`.
+/// The variants are ordered clockwise starting at `North`.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+use Direction::*;
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from `North`.
+    pub const CARDINAL: [Direction; 4] = [North, East, South, West];
+    /// All eight directions, clockwise from `North`.
+    pub const ALL: [Direction; 8] = [
+        North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest,
+    ];
+
+    fn rotate(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % Direction::ALL.len()]
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Turns 90 degrees counter clockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+
+    /// Offset of one step in this direction.
+    pub fn delta<T: Ord + Signed>(self) -> PointT<T> {
+        let (x, y) = match self {
+            North => (0, -1),
+            NorthEast => (1, -1),
+            East => (1, 0),
+            SouthEast => (1, 1),
+            South => (0, 1),
+            SouthWest => (-1, 1),
+            West => (-1, 0),
+            NorthWest => (-1, -1),
+        };
+        let unit = |v: i8| match v {
+            -1 => -T::one(),
+            0 => T::zero(),
+            _ => T::one(),
+        };
+        PointT {
+            x: unit(x),
+            y: unit(y),
+        }
+    }
+
+    /// Direction of a single step `delta`, `None` for anything else.
+    pub fn from_delta<T: Ord + Signed>(delta: &PointT<T>) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|d| d.delta::<T>() == *delta)
+    }
+
+    /// Arrow of the cardinal directions as used in puzzle inputs: `^`, `>`, `v` and `<`.
+    pub fn glyph(self) -> Option<char> {
+        match self {
+            North => Some('^'),
+            East => Some('>'),
+            South => Some('v'),
+            West => Some('<'),
+            _ => None,
+        }
+    }
+
+    pub fn from_glyph(c: char) -> Option<Direction> {
+        Direction::CARDINAL
+            .into_iter()
+            .find(|d| d.glyph() == Some(c))
+    }
+}
+
+impl<T> PointT<T>
+where
+    T: Ord + Copy + Signed,
+{
+    pub fn step(&self, dir: Direction) -> PointT<T> {
+        *self + dir.delta()
+    }
+
+    /// The four orthogonal neighbours, clockwise from north.
+    pub fn neighbours(&self) -> impl Iterator<Item = PointT<T>> + '_ {
+        Direction::CARDINAL.into_iter().map(|d| self.step(d))
+    }
+
+    /// All eight surrounding points, clockwise from north.
+    pub fn neighbours8(&self) -> impl Iterator<Item = PointT<T>> + '_ {
+        Direction::ALL.into_iter().map(|d| self.step(d))
+    }
+}
+
+impl<T> ops::Add<Direction> for PointT<T>
+where
+    T: Ord + Copy + Signed,
+{
+    type Output = PointT<T>;
+
+    fn add(self, dir: Direction) -> PointT<T> {
+        self + dir.delta()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_turning() {
+        assert!(North.turn_right() == East);
+        assert!(North.turn_left() == West);
+        assert!(West.turn_right() == North);
+        assert!(NorthEast.turn_right() == SouthEast);
+        assert!(SouthWest.reverse() == NorthEast);
+        assert!(Direction::ALL.iter().filter(|d| d.is_cardinal()).count() == 4);
+        for d in Direction::ALL {
+            assert!(d.turn_left().turn_right() == d);
+            assert!(d.reverse().reverse() == d);
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert!(North.delta::<i32>() == PointT { x: 0, y: -1 });
+        assert!(SouthWest.delta::<i64>() == PointT { x: -1, y: 1 });
+        for d in Direction::ALL {
+            assert!(Direction::from_delta(&d.delta::<i64>()) == Some(d));
+        }
+        assert!(Direction::from_delta(&PointT { x: 2, y: 0 }).is_none());
+        assert!(Direction::from_glyph('v') == Some(South));
+        assert!(Direction::from_glyph('x').is_none());
+        assert!(NorthWest.glyph().is_none());
+        assert!(East.glyph().and_then(Direction::from_glyph) == Some(East));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = PointT { x: 2, y: 5 };
+        assert!(p.step(East) == PointT { x: 3, y: 5 });
+        assert!(p + North == PointT { x: 2, y: 4 });
+        let n = p.neighbours().collect::<Vec<_>>();
+        assert!(
+            n == [
+                PointT { x: 2, y: 4 },
+                PointT { x: 3, y: 5 },
+                PointT { x: 2, y: 6 },
+                PointT { x: 1, y: 5 }
+            ]
+        );
+        assert!(p.neighbours8().count() == 8);
+        assert!(p.neighbours8().all(|n| n.distance(&p) <= 2 && n != p));
+    }
+}
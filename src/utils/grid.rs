@@ -0,0 +1,247 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use num::PrimInt;
+
+use super::{Direction, PointT, RectT};
+use crate::parse::ParseError;
+
+/// Rectangular map stored row by row, indexed by `PointT` with `(0, 0)` top left.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Grid of the raw characters of `input`.
+    pub fn parse_chars(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(input, "a character", Some)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Maps every character of `input` through `cell`. A `None` from `cell` or a line
+    /// with a different width than the first one is reported as a `ParseError`.
+    pub fn parse<F>(input: &str, expected: &str, mut cell: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (y, line) in input.lines().enumerate() {
+            let mut line_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => return Err(ParseError::new(y, x, expected)),
+                }
+                line_width += 1;
+            }
+            if y == 0 {
+                width = line_width;
+            } else if line_width != width {
+                return Err(ParseError::new(
+                    y,
+                    line_width.min(width),
+                    "a uniform map width",
+                ));
+            }
+            height += 1;
+        }
+        if width == 0 {
+            return Err(ParseError::at_end(input, "a map"));
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds<I: PrimInt>(&self) -> RectT<I> {
+        RectT {
+            x: I::zero(),
+            y: I::zero(),
+            width: I::from(self.width).expect("Grid width out of range"),
+            height: I::from(self.height).expect("Grid height out of range"),
+        }
+    }
+
+    pub fn contains<I: PrimInt>(&self, p: &PointT<I>) -> bool {
+        self.index_of(p).is_some()
+    }
+
+    pub fn get<I: PrimInt>(&self, p: &PointT<I>) -> Option<&T> {
+        self.index_of(p).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut<I: PrimInt>(&mut self, p: &PointT<I>) -> Option<&mut T> {
+        self.index_of(p).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Orthogonal neighbours of `p` (north, east, south, west) that are inside the grid.
+    pub fn neighbours<I: PrimInt>(&self, p: &PointT<I>) -> impl Iterator<Item = PointT<I>> + '_ {
+        let x = p.x.to_i64();
+        let y = p.y.to_i64();
+        Direction::CARDINAL.into_iter().filter_map(move |dir| {
+            let d = dir.delta::<i64>();
+            let n = PointT {
+                x: I::from(x? + d.x)?,
+                y: I::from(y? + d.y)?,
+            };
+            self.contains(&n).then_some(n)
+        })
+    }
+
+    /// Grid of the same size with `f` applied to every cell.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// All cells with their position, row by row.
+    pub fn iter<I: PrimInt>(&self) -> impl Iterator<Item = (PointT<I>, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (self.point_of(idx), cell))
+    }
+
+    /// Positions of all cells matching `predicate`, row by row.
+    pub fn positions<'a, I, P>(&'a self, predicate: P) -> impl Iterator<Item = PointT<I>> + 'a
+    where
+        I: PrimInt + 'a,
+        P: Fn(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter_map(move |(p, cell)| predicate(cell).then_some(p))
+    }
+
+    /// Position of the first cell equal to `marker`.
+    pub fn find<I: PrimInt>(&self, marker: &T) -> Option<PointT<I>>
+    where
+        T: PartialEq,
+    {
+        self.positions(|cell| cell == marker).next()
+    }
+
+    /// Like `find`, but also overwrites the marker with `replacement`.
+    pub fn extract<I: PrimInt>(&mut self, marker: &T, replacement: T) -> Option<PointT<I>>
+    where
+        T: PartialEq,
+    {
+        let idx = self.cells.iter().position(|cell| cell == marker)?;
+        self.cells[idx] = replacement;
+        Some(self.point_of(idx))
+    }
+
+    fn index_of<I: PrimInt>(&self, p: &PointT<I>) -> Option<usize> {
+        let x = p.x.to_usize()?;
+        let y = p.y.to_usize()?;
+        (x < self.width && y < self.height).then(|| x + y * self.width)
+    }
+
+    fn point_of<I: PrimInt>(&self, idx: usize) -> PointT<I> {
+        PointT {
+            x: I::from(idx % self.width).expect("Grid coordinate out of range"),
+            y: I::from(idx / self.width).expect("Grid coordinate out of range"),
+        }
+    }
+}
+
+impl<T, I: PrimInt> Index<&PointT<I>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: &PointT<I>) -> &T {
+        self.get(p).expect("Point outside of the grid")
+    }
+}
+
+impl<T, I: PrimInt> IndexMut<&PointT<I>> for Grid<T> {
+    fn index_mut(&mut self, p: &PointT<I>) -> &mut T {
+        self.get_mut(p).expect("Point outside of the grid")
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#S.\n.E#\n", "a tile", |c| "#.SE".contains(c).then_some(c))
+            .expect("Invalid grid");
+        assert!(grid.width() == 3 && grid.height() == 2);
+        assert!(grid[&PointT { x: 1, y: 1 }] == 'E');
+        assert!(grid.get(&PointT { x: -1, y: 0 }).is_none());
+        assert!(grid.get(&PointT { x: 3_u8, y: 0 }).is_none());
+        assert!(grid.find(&'S') == Some(PointT { x: 1_i64, y: 0 }));
+        assert!(grid.to_string() == "#S.\n.E#\n");
+
+        let err = Grid::parse("#.\n#x", "a tile", |c| "#.".contains(c).then_some(c)).err();
+        assert!(err == Some(ParseError::new(1, 1, "a tile")));
+        let err = Grid::parse_chars("#.\n#").err();
+        assert!(err == Some(ParseError::new(1, 1, "a uniform map width")));
+        assert!(Grid::parse_chars("").is_err());
+    }
+
+    #[test]
+    fn test_neighbours_and_markers() {
+        let mut grid = Grid::parse_chars("S..\n...\n..E").expect("Invalid grid");
+        let corner = grid.neighbours(&PointT { x: 0, y: 0 }).collect::<Vec<_>>();
+        assert!(corner == [PointT { x: 1, y: 0 }, PointT { x: 0, y: 1 }]);
+        assert!(grid.neighbours(&PointT { x: 1_u32, y: 1 }).count() == 4);
+
+        assert!(grid.extract(&'E', '.') == Some(PointT { x: 2_i32, y: 2 }));
+        assert!(grid.find::<i32>(&'E').is_none());
+        assert!(grid.positions::<i32, _>(|c| *c == '.').count() == 8);
+        assert!(
+            grid.bounds::<i64>()
+                == RectT {
+                    x: 0,
+                    y: 0,
+                    width: 3,
+                    height: 3
+                }
+        );
+    }
+}
@@ -0,0 +1,317 @@
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::event;
+
+/// Distances from the start node and, for every reached node, all predecessors on a
+/// shortest path to it.
+pub struct ShortestPaths<N> {
+    nodes: HashMap<N, (i64, Vec<N>)>,
+}
+
+impl<N: Eq + Hash + Clone> ShortestPaths<N> {
+    fn new(start: N) -> ShortestPaths<N> {
+        ShortestPaths {
+            nodes: HashMap::from([(start, (0, Vec::new()))]),
+        }
+    }
+
+    /// Records the edge `from -> to`, returns true when `to` got a shorter distance.
+    /// An edge of equal distance adds a predecessor unless it is a zero cost edge
+    /// back to an ancestor of `from`, which would close a cycle.
+    fn relax(&mut self, from: &N, to: N, distance: i64) -> bool {
+        let equal = self.distance(&to) == Some(distance);
+        if equal && self.is_ancestor(&to, from) {
+            return false;
+        }
+        match self.nodes.entry(to) {
+            Entry::Occupied(mut o) => {
+                let (d, predecessors) = o.get_mut();
+                if distance < *d {
+                    *d = distance;
+                    *predecessors = vec![from.clone()];
+                    true
+                } else {
+                    if equal {
+                        predecessors.push(from.clone());
+                    }
+                    false
+                }
+            }
+            Entry::Vacant(v) => {
+                v.insert((distance, vec![from.clone()]));
+                true
+            }
+        }
+    }
+
+    /// Whether `node` is `of` or precedes it on a shortest path. Predecessors are
+    /// never farther than their successors, so only nodes as far as `node` are
+    /// followed.
+    fn is_ancestor(&self, node: &N, of: &N) -> bool {
+        let Some(distance) = self.distance(node) else {
+            return false;
+        };
+        let mut seen = HashSet::new();
+        let mut todo = vec![of];
+        while let Some(current) = todo.pop() {
+            if current == node {
+                return true;
+            }
+            if seen.insert(current) {
+                let predecessors = self.predecessors(current).iter();
+                todo.extend(predecessors.filter(|p| self.distance(p) == Some(distance)));
+            }
+        }
+        false
+    }
+
+    pub fn distance(&self, node: &N) -> Option<i64> {
+        self.nodes.get(node).map(|(d, _)| *d)
+    }
+
+    pub fn distances(&self) -> impl Iterator<Item = (&N, i64)> {
+        self.nodes.iter().map(|(n, (d, _))| (n, *d))
+    }
+
+    pub fn into_distances(self) -> HashMap<N, i64> {
+        self.nodes.into_iter().map(|(n, (d, _))| (n, d)).collect()
+    }
+
+    /// Previous nodes of all shortest paths to `node`, empty for the start node.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.nodes.get(node).map_or(&[], |(_, p)| &p[..])
+    }
+
+    /// One shortest path from the start to `target`, both included.
+    pub fn path(&self, target: &N) -> Option<Vec<N>> {
+        self.nodes.get(target)?;
+        let mut ret = vec![target.clone()];
+        while let Some(prev) = self.predecessors(ret.last()?).first() {
+            ret.push(prev.clone());
+        }
+        ret.reverse();
+        Some(ret)
+    }
+
+    /// Every node that lies on any shortest path to one of `targets`.
+    pub fn nodes_on_paths<'a>(&'a self, targets: impl IntoIterator<Item = &'a N>) -> HashSet<N> {
+        let mut ret = HashSet::new();
+        let mut todo: Vec<&N> = targets
+            .into_iter()
+            .filter(|t| self.nodes.contains_key(t))
+            .collect();
+        while let Some(node) = todo.pop() {
+            if ret.insert(node.clone()) {
+                todo.extend(self.predecessors(node));
+            }
+        }
+        ret
+    }
+}
+
+struct QueueItem<N> {
+    priority: i64,
+    distance: i64,
+    node: N,
+}
+
+impl<N> Ord for QueueItem<N> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N> PartialOrd for QueueItem<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> PartialEq for QueueItem<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for QueueItem<N> {}
+
+/// Shared loop of `dijkstra` and `astar`, stops after the first goal node is taken
+/// from the queue and returns it.
+fn search<N, S, I, H, G>(
+    start: N,
+    mut successors: S,
+    heuristic: H,
+    is_goal: G,
+) -> (ShortestPaths<N>, Option<N>)
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, i64)>,
+    H: Fn(&N) -> i64,
+    G: Fn(&N) -> bool,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+    let mut pq = BinaryHeap::from([QueueItem {
+        priority: heuristic(&start),
+        distance: 0,
+        node: start,
+    }]);
+    let mut popped = 0;
+    while let Some(item) = pq.pop() {
+        if paths.distance(&item.node) != Some(item.distance) {
+            continue;
+        }
+        popped += 1;
+        event!(
+            Trace,
+            "pop distance={} priority={}",
+            item.distance,
+            item.priority
+        );
+        if is_goal(&item.node) {
+            event!(Debug, "goal distance={} popped={}", item.distance, popped);
+            return (paths, Some(item.node));
+        }
+        for (next, cost) in successors(&item.node) {
+            let distance = item.distance + cost;
+            if paths.relax(&item.node, next.clone(), distance) {
+                pq.push(QueueItem {
+                    priority: distance + heuristic(&next),
+                    distance,
+                    node: next,
+                });
+            }
+        }
+    }
+    event!(
+        Debug,
+        "exhausted popped={} reached={}",
+        popped,
+        paths.nodes.len()
+    );
+    (paths, None)
+}
+
+/// Shortest paths from `start` to every reachable node, `successors` returns the
+/// neighbours of a node with the (non negative) cost to move there.
+pub fn dijkstra<N, S, I>(start: N, successors: S) -> ShortestPaths<N>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, i64)>,
+{
+    search(start, successors, |_| 0, |_| false).0
+}
+
+/// Shortest path from `start` to the closest node accepted by `is_goal`. `heuristic`
+/// must never overestimate the remaining cost. Returns the cost and the path.
+pub fn astar<N, S, I, H, G>(
+    start: N,
+    successors: S,
+    heuristic: H,
+    is_goal: G,
+) -> Option<(i64, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, i64)>,
+    H: Fn(&N) -> i64,
+    G: Fn(&N) -> bool,
+{
+    let (paths, goal) = search(start, successors, heuristic, is_goal);
+    let goal = goal?;
+    Some((paths.distance(&goal)?, paths.path(&goal)?))
+}
+
+/// Shortest paths from `start` when every step costs 1.
+pub fn bfs<N, S, I>(start: N, mut successors: S) -> ShortestPaths<N>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let distance = paths.nodes[&node].0 + 1;
+        for next in successors(&node) {
+            if paths.relax(&node, next.clone(), distance) {
+                queue.push_back(next);
+            }
+        }
+    }
+    event!(Debug, "bfs reached={}", paths.nodes.len());
+    paths
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 0 -> 1 -> 3 and 0 -> 2 -> 3 are both 2 long, 0 -> 3 directly costs 5
+    fn successors(n: &i32) -> Vec<(i32, i64)> {
+        match n {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 | 2 => vec![(3, 1)],
+            3 => vec![(4, 2)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra(0, successors);
+        assert!(paths.distance(&3) == Some(2));
+        assert!(paths.distance(&4) == Some(4));
+        assert!(paths.distance(&5).is_none());
+        let mut pred = paths.predecessors(&3).to_vec();
+        pred.sort();
+        assert!(pred == [1, 2]);
+        let path = paths.path(&4).expect("No path");
+        assert!(path.len() == 4 && path[0] == 0 && path[2] == 3);
+        assert!(paths.nodes_on_paths([&3]) == HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_zero_cost_cycle() {
+        // 0, 1 and 2 are connected both ways for free, only 2 -> 3 costs
+        let paths = dijkstra(0, |n: &i32| match n {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (2, 0)],
+            2 => vec![(1, 0), (3, 1)],
+            _ => vec![],
+        });
+        assert!(paths.predecessors(&0).is_empty());
+        assert!(paths.predecessors(&1) == [0]);
+        assert!(paths.path(&3) == Some(vec![0, 1, 2, 3]));
+        assert!(paths.nodes_on_paths([&3]) == HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_zero_cost_predecessor() {
+        // B reaches A for free, so it is on a shortest path to A whichever of the
+        // two leaves the queue first
+        for order in [[1, 2], [2, 1]] {
+            let paths = dijkstra(0, |n: &i32| match n {
+                0 => vec![(order[0], 1), (order[1], 1)],
+                2 => vec![(1, 0)],
+                _ => vec![],
+            });
+            assert!(paths.distance(&1) == Some(1));
+            assert!(paths.nodes_on_paths([&1]) == HashSet::from([0, 1, 2]));
+        }
+    }
+
+    #[test]
+    fn test_bfs_and_astar() {
+        let paths = bfs(0, |n| successors(n).into_iter().map(|(n, _)| n));
+        assert!(paths.distance(&3) == Some(1));
+        assert!(paths.path(&4) == Some(vec![0, 3, 4]));
+
+        let found = astar(0, successors, |n| (4 - n).max(0) as i64 / 2, |n| *n == 4);
+        assert!(found.map(|(cost, path)| (cost, path.len())) == Some((4, 4)));
+        assert!(astar(0, successors, |_| 0, |n| *n == 7).is_none());
+    }
+}
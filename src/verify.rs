@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::parse::{parse_in_line, ParseError};
+use crate::solution::Answers;
+
+/// Answers known to be correct for the checked in inputs, keyed by day and part.
+#[derive(PartialEq, Eq, Debug, Default)]
+pub struct KnownAnswers {
+    answers: HashMap<(u32, u32), String>,
+}
+
+/// Result of comparing one part against the known answers.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Check {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    /// Nothing recorded for this part yet.
+    Unknown,
+    /// Nothing recorded and the solver does not produce an answer either.
+    Unsolved,
+}
+
+impl KnownAnswers {
+    /// One `DAY PART ANSWER` entry per line, `#` starts a comment line.
+    pub fn parse(input: &str) -> Result<KnownAnswers, ParseError> {
+        let mut answers = HashMap::new();
+        for (y, line) in input.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.splitn(3, ' ');
+            let (Some(day), Some(part), Some(answer)) = (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(ParseError::new(y, line.len(), "DAY PART ANSWER"));
+            };
+            let day: u32 = parse_in_line(y, line, day, "a day")?;
+            let part = match parse_in_line(y, line, part, "a part")? {
+                part @ 1..=2 => part,
+                _ => return Err(ParseError::in_line(y, line, part, "part 1 or 2")),
+            };
+            if answers.insert((day, part), answer.to_string()).is_some() {
+                return Err(ParseError::new(y, 0, "a single answer per day and part"));
+            }
+        }
+        Ok(KnownAnswers { answers })
+    }
+
+    pub fn read(path: &Path) -> Result<KnownAnswers, String> {
+        let input = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        KnownAnswers::parse(&input).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &str) -> Check {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Check::Correct,
+            Some(expected) => Check::Wrong {
+                expected: expected.to_string(),
+            },
+            None if answer.is_empty() => Check::Unsolved,
+            None => Check::Unknown,
+        }
+    }
+
+    /// Checks both parts of `answers`.
+    pub fn check_answers(&self, answers: &Answers) -> [Check; 2] {
+        [
+            self.check(answers.day, 1, &answers.part1),
+            self.check(answers.day, 2, &answers.part2),
+        ]
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::Correct => write!(f, "ok"),
+            Check::Wrong { expected } => write!(f, "WRONG, expected {}", expected),
+            Check::Unknown => write!(f, "missing, no known answer"),
+            Check::Unsolved => write!(f, "missing, not solved"),
+        }
+    }
+}
+
+/// `answers.txt` in the crate root.
+pub fn default_answers_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.txt")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_parse() {
+        let known = KnownAnswers::parse("# comment\n17 1 3,5,0\n23 2 a b\n\n").expect("Invalid");
+        assert!(known.get(17, 1) == Some("3,5,0"));
+        assert!(known.get(23, 2) == Some("a b"));
+        assert!(known.get(17, 2).is_none());
+
+        let err = KnownAnswers::parse("1 3 42").err();
+        assert!(err == Some(ParseError::new(0, 2, "part 1 or 2")));
+        let err = KnownAnswers::parse("1 1 42\n1 1 43").err();
+        assert!(err == Some(ParseError::new(1, 0, "a single answer per day and part")));
+        assert!(KnownAnswers::parse("1 1").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let known = KnownAnswers::parse("24 1 480").expect("Invalid");
+        assert!(known.check(24, 1, "480") == Check::Correct);
+        assert!(
+            known.check(24, 1, "")
+                == Check::Wrong {
+                    expected: "480".to_string()
+                }
+        );
+        assert!(known.check(24, 2, "") == Check::Unsolved);
+        assert!(known.check(24, 2, "z00") == Check::Unknown);
+    }
+
+    #[test]
+    fn test_checked_in_answers() {
+        let known = KnownAnswers::read(&default_answers_path()).expect("Invalid answers file");
+        assert!(known.get(1, 1).is_some());
+    }
+}